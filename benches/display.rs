@@ -23,6 +23,14 @@ fn benchmark_stylic() {
     black_box_fmt(styled!("Hello").bold().red());
 }
 
+fn benchmark_stylic_static() {
+    use stylic::{StaticStyle, Style};
+
+    static STYLE: StaticStyle = StaticStyle::new(Style::new().bold().red());
+
+    black_box_fmt(STYLE.apply("Hello"));
+}
+
 fn _benchmark_colored() {
     use colored::Colorize;
 
@@ -99,11 +107,98 @@ fn benchmark_yansi_error_message() {
     ));
 }
 
+fn benchmark_stylic_rgb() {
+    use stylic::styled;
+
+    black_box_fmt(
+        styled!("Hello")
+            .bold()
+            .rgb_color(255, 136, 0)
+            .on_color_rgb(30, 30, 30),
+    );
+}
+
+fn benchmark_stylic_rgb_static() {
+    use stylic::{StaticStyle, Style};
+
+    static STYLE: StaticStyle = StaticStyle::new(
+        Style::new()
+            .bold()
+            .rgb_color(255, 136, 0)
+            .on_color_rgb(30, 30, 30),
+    );
+
+    black_box_fmt(STYLE.apply("Hello"));
+}
+
+fn benchmark_owo_colors_rgb() {
+    use owo_colors::OwoColorize;
+    use owo_colors::Style;
+
+    black_box_fmt(
+        "Hello".style(
+            Style::new()
+                .bold()
+                .truecolor(255, 136, 0)
+                .on_truecolor(30, 30, 30),
+        ),
+    );
+}
+
+fn benchmark_yansi_rgb() {
+    use yansi::Paint;
+
+    black_box_fmt("Hello".bold().rgb(255, 136, 0).on_rgb(30, 30, 30));
+}
+
+fn benchmark_stylic_ansi256() {
+    use stylic::styled;
+
+    black_box_fmt(
+        styled!("Hello")
+            .bold()
+            .ansi256_color(208)
+            .on_color_ansi256(236),
+    );
+}
+
+fn benchmark_owo_colors_ansi256() {
+    use owo_colors::{OwoColorize, Style, XtermColors};
+
+    black_box_fmt(
+        "Hello".style(
+            Style::new()
+                .bold()
+                .color(XtermColors::from(208))
+                .on_color(XtermColors::from(236)),
+        ),
+    );
+}
+
+fn benchmark_yansi_ansi256() {
+    use yansi::Paint;
+
+    black_box_fmt("Hello".bold().fixed(208).on_fixed(236));
+}
+
+fn benchmark_stylic_hex() {
+    use stylic::Styled;
+
+    black_box_fmt(format_args!("{:#x}", Styled::new(0xbeef).bold().red()));
+}
+
+fn benchmark_owo_colors_hex() {
+    use owo_colors::OwoColorize;
+
+    black_box_fmt(format_args!("{:#x}", 0xbeef.bold().red()));
+}
+
 fn benchmark(c: &mut Criterion) {
     colored::control::set_override(true);
     stylic::set_style_mode(stylic::StyleMode::enable());
 
     c.bench_function("stylic", |b| b.iter(benchmark_stylic));
+    c.bench_function("stylic-static", |b| b.iter(benchmark_stylic_static));
     // c.bench_function("colored", |b| b.iter(benchmark_colored));
     c.bench_function("owo-colors-generic", |b| {
         b.iter(benchmark_owo_colors_generic)
@@ -127,5 +222,38 @@ fn benchmark_error_message(c: &mut Criterion) {
     c.bench_function("err_yansi", |b| b.iter(benchmark_yansi_error_message));
 }
 
-criterion_group!(benches, benchmark, benchmark_error_message);
+fn benchmark_rgb(c: &mut Criterion) {
+    stylic::set_style_mode(stylic::StyleMode::enable());
+
+    c.bench_function("rgb_stylic", |b| b.iter(benchmark_stylic_rgb));
+    c.bench_function("rgb_stylic-static", |b| b.iter(benchmark_stylic_rgb_static));
+    c.bench_function("rgb_owo-colors", |b| b.iter(benchmark_owo_colors_rgb));
+    c.bench_function("rgb_yansi", |b| b.iter(benchmark_yansi_rgb));
+}
+
+fn benchmark_ansi256(c: &mut Criterion) {
+    stylic::set_style_mode(stylic::StyleMode::enable());
+
+    c.bench_function("ansi256_stylic", |b| b.iter(benchmark_stylic_ansi256));
+    c.bench_function("ansi256_owo-colors", |b| {
+        b.iter(benchmark_owo_colors_ansi256)
+    });
+    c.bench_function("ansi256_yansi", |b| b.iter(benchmark_yansi_ansi256));
+}
+
+fn benchmark_hex(c: &mut Criterion) {
+    stylic::set_style_mode(stylic::StyleMode::enable());
+
+    c.bench_function("hex_stylic", |b| b.iter(benchmark_stylic_hex));
+    c.bench_function("hex_owo-colors", |b| b.iter(benchmark_owo_colors_hex));
+}
+
+criterion_group!(
+    benches,
+    benchmark,
+    benchmark_error_message,
+    benchmark_rgb,
+    benchmark_ansi256,
+    benchmark_hex
+);
 criterion_main!(benches);
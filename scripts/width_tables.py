@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+"""Generate the character width tables in `src/width/tables.rs`.
+
+Usage: python3 scripts/width_tables.py > src/width/tables.rs
+
+The tables are generated from the Unicode database that comes with Python, so the
+Python version decides the Unicode version. Python 3.11 comes with Unicode 14.0.0.
+"""
+
+import sys
+import unicodedata
+
+UNICODE_VERSION = "14.0.0"
+
+if unicodedata.unidata_version != UNICODE_VERSION:
+    sys.exit(
+        f"expected Unicode {UNICODE_VERSION}, but this Python has Unicode "
+        f"{unicodedata.unidata_version}; update UNICODE_VERSION to regenerate the tables"
+    )
+
+
+def ranges(predicate):
+    result = []
+    for cp in range(sys.maxunicode + 1):
+        if not predicate(chr(cp)):
+            continue
+        if result and result[-1][1] == cp - 1:
+            result[-1][1] = cp
+        else:
+            result.append([cp, cp])
+    return result
+
+
+def is_zero_width(c):
+    cp = ord(c)
+    category = unicodedata.category(c)
+
+    # Combining marks and format characters such as zero width joiners, except the
+    # soft hyphen, which is usually displayed.
+    if category in ("Mn", "Me") or (category == "Cf" and cp != 0xAD):
+        return True
+
+    # Hangul vowels and final consonants, which combine with the preceding syllable.
+    return 0x1160 <= cp <= 0x11FF or 0xD7B0 <= cp <= 0xD7FF
+
+
+def is_wide(c):
+    # This includes emoji with a default emoji presentation.
+    return unicodedata.east_asian_width(c) in ("W", "F") and not is_zero_width(c)
+
+
+def print_table(name, doc, table):
+    print(f"/// {doc}")
+    print(f"pub(super) static {name}: &[(char, char)] = &[")
+    for start, end in table:
+        print(f"    ('\\u{{{start:x}}}', '\\u{{{end:x}}}'),")
+    print("];")
+
+
+print(f"// Generated by `scripts/width_tables.py` from Unicode {unicodedata.unidata_version}.")
+print("// Do not edit by hand.")
+print()
+print_table("ZERO_WIDTH", "Characters that take up no space.", ranges(is_zero_width))
+print()
+print_table("WIDE", "Characters that take up two columns.", ranges(is_wide))
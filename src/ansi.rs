@@ -0,0 +1,370 @@
+use crate::{AnsiColor, Attributes, Color, Style, UnderlineStyle};
+
+/// An event produced by [`parse_ansi`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiEvent<'a> {
+    /// Text, with the style that applies to it.
+    Text(Style, &'a str),
+
+    /// The start of a hyperlink to the given URI.
+    ///
+    /// A hyperlink that starts while another is open replaces it.
+    HyperlinkStart(&'a str),
+
+    /// The end of a hyperlink.
+    HyperlinkEnd,
+}
+
+/// Parse text that contains ANSI escape sequences, such as the output of another program.
+///
+/// Returns an iterator of [`AnsiEvent`]s. SGR sequences (`ESC [ ... m`) are turned back into
+/// [`Style`]s, which are attached to the text that follows them, and OSC 8 sequences
+/// are turned into hyperlink events. Any other escape sequences are skipped.
+///
+/// Malformed input never causes an error. Unknown or invalid SGR parameters are ignored,
+/// sequences that contain unexpected characters are ended just before them, and an
+/// unterminated sequence at the end of the input is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{parse_ansi, AnsiEvent, Style};
+///
+/// let events: Vec<_> = parse_ansi("plain \x1b[1;31mbold red\x1b[0m").collect();
+/// assert_eq!(
+///     events,
+///     [
+///         AnsiEvent::Text(Style::new(), "plain "),
+///         AnsiEvent::Text(Style::new().bold().red(), "bold red"),
+///     ]
+/// );
+/// ```
+#[inline]
+pub fn parse_ansi(s: &str) -> AnsiParser<'_> {
+    AnsiParser {
+        rest: s,
+        style: Style::new(),
+        in_hyperlink: false,
+    }
+}
+
+/// An iterator over the [`AnsiEvent`]s in a string. See [`parse_ansi`].
+#[derive(Debug, Clone)]
+pub struct AnsiParser<'a> {
+    rest: &'a str,
+    style: Style,
+    in_hyperlink: bool,
+}
+
+impl<'a> AnsiParser<'a> {
+    /// The style that applies to the text that hasn't been parsed yet.
+    #[inline]
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The text that hasn't been parsed yet.
+    #[inline]
+    pub fn remainder(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Parse an escape sequence at the start of the remaining text, which starts with
+    /// `ESC`, returning an event if it produced one.
+    fn parse_escape(&mut self) -> Option<AnsiEvent<'a>> {
+        let rest = &self.rest[1..];
+
+        match rest.as_bytes().first() {
+            Some(b'[') => {
+                let (params, intermediates, len) = split_csi(&rest[1..]);
+                self.rest = &rest[1 + len..];
+
+                // SGR parameters consist of digits and separators only. Anything else
+                // (such as private parameters) is a different sequence.
+                let is_sgr = len > params.len() + intermediates.len()
+                    && rest.as_bytes()[len] == b'm'
+                    && intermediates.is_empty()
+                    && params
+                        .bytes()
+                        .all(|b| matches!(b, b'0'..=b'9' | b';' | b':'));
+
+                if is_sgr {
+                    apply_sgr(&mut self.style, params);
+                }
+
+                None
+            }
+
+            Some(b']') => {
+                let (payload, len) = split_osc(&rest[1..]);
+                self.rest = &rest[1 + len..];
+
+                self.parse_osc(payload?)
+            }
+
+            // Other escape sequences are intermediate bytes followed by a final byte.
+            Some(_) => {
+                let len =
+                    rest.bytes()
+                        .position(|b| !matches!(b, b' '..=b'/'))
+                        .map_or(rest.len(), |i| match rest.as_bytes()[i] {
+                            b'0'..=b'~' => i + 1,
+                            _ => i,
+                        });
+                self.rest = &rest[len..];
+
+                None
+            }
+
+            None => {
+                self.rest = rest;
+                None
+            }
+        }
+    }
+
+    fn parse_osc(&mut self, payload: &'a str) -> Option<AnsiEvent<'a>> {
+        // Hyperlinks are `8 ; params ; uri`, where the params are ignored.
+        let (_, uri) = payload.strip_prefix("8;")?.split_once(';')?;
+
+        if !uri.is_empty() {
+            self.in_hyperlink = true;
+            Some(AnsiEvent::HyperlinkStart(uri))
+        } else if self.in_hyperlink {
+            self.in_hyperlink = false;
+            Some(AnsiEvent::HyperlinkEnd)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for AnsiParser<'a> {
+    type Item = AnsiEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.rest.is_empty() {
+            let text_len = self.rest.find('\x1b').unwrap_or(self.rest.len());
+
+            if text_len > 0 {
+                let (text, rest) = self.rest.split_at(text_len);
+                self.rest = rest;
+                return Some(AnsiEvent::Text(self.style, text));
+            }
+
+            if let Some(event) = self.parse_escape() {
+                return Some(event);
+            }
+        }
+
+        None
+    }
+}
+
+/// Split the body of a control sequence into its parameters and intermediate bytes,
+/// and return the length of the sequence, including the final byte if there is one.
+fn split_csi(s: &str) -> (&str, &str, usize) {
+    let bytes = s.as_bytes();
+
+    let params_len = bytes
+        .iter()
+        .position(|b| !matches!(b, b'0'..=b'?'))
+        .unwrap_or(bytes.len());
+
+    let intermediates_len = bytes[params_len..]
+        .iter()
+        .position(|b| !matches!(b, b' '..=b'/'))
+        .unwrap_or(bytes.len() - params_len);
+
+    let end = params_len + intermediates_len;
+
+    // A sequence with an invalid final byte ends before it, so that it is kept as text.
+    let len = match bytes.get(end) {
+        Some(b'@'..=b'~') => end + 1,
+        _ => end,
+    };
+
+    (&s[..params_len], &s[params_len..end], len)
+}
+
+/// Split the body of an operating system command into its payload and the length of the
+/// command, including its terminator. The payload is `None` if the command wasn't
+/// terminated.
+fn split_osc(s: &str) -> (Option<&str>, usize) {
+    let bytes = s.as_bytes();
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b'\x07' => return (Some(&s[..i]), i + 1),
+            b'\x1b' if bytes.get(i + 1) == Some(&b'\\') => return (Some(&s[..i]), i + 2),
+
+            // Any other escape sequence interrupts the command.
+            b'\x1b' => return (None, i),
+
+            _ => {}
+        }
+    }
+
+    (None, s.len())
+}
+
+/// Apply the parameters of an SGR sequence to a style. Returns `false` if any of them
+/// couldn't be represented by the style, and were ignored.
+pub(crate) fn apply_sgr(style: &mut Style, params: &str) -> bool {
+    // An empty sequence is the same as a reset.
+    if params.is_empty() {
+        *style = Style::new();
+        return true;
+    }
+
+    let mut known = true;
+
+    let remove = |style: &mut Style, attributes: Attributes| {
+        style.attributes = style.attributes.and(attributes.not());
+
+        // Keep the style equal to one created with the builder methods.
+        if attributes.contains(Attributes::UNDERLINED) {
+            style.underline_style = UnderlineStyle::Single;
+        }
+    };
+
+    let mut params = params.split(';');
+
+    while let Some(param) = params.next() {
+        let mut subparams = param.split(':');
+        let code = subparams.next().and_then(parse_param);
+
+        match code {
+            // An empty parameter is the same as `0`.
+            Some(0) => *style = Style::new(),
+            None if param.is_empty() => *style = Style::new(),
+
+            Some(1) => *style = style.bold(),
+            Some(2) => *style = style.dim(),
+            Some(3) => *style = style.italic(),
+            Some(4) => match subparams.next().map(parse_param) {
+                None => *style = style.underline_style(UnderlineStyle::Single),
+                Some(Some(0)) => remove(
+                    style,
+                    Attributes::UNDERLINED | Attributes::DOUBLE_UNDERLINED,
+                ),
+                Some(Some(1)) => *style = style.underline_style(UnderlineStyle::Single),
+                Some(Some(2)) => *style = style.double_underlined(),
+                Some(Some(3)) => *style = style.underline_style(UnderlineStyle::Curly),
+                Some(Some(4)) => *style = style.underline_style(UnderlineStyle::Dotted),
+                Some(Some(5)) => *style = style.underline_style(UnderlineStyle::Dashed),
+                Some(_) => known = false,
+            },
+            Some(5) => *style = style.blinking(),
+            Some(6) => *style = style.rapid_blinking(),
+            Some(7) => *style = style.inverted(),
+            Some(8) => *style = style.hidden(),
+            Some(9) => *style = style.crossed(),
+            Some(21) => *style = style.double_underlined(),
+            Some(51) => *style = style.framed(),
+            Some(52) => *style = style.encircled(),
+            Some(53) => *style = style.overlined(),
+            Some(73) => *style = style.superscript(),
+            Some(74) => *style = style.subscript(),
+
+            Some(22) => remove(style, Attributes::BOLD | Attributes::DIM),
+            Some(23) => remove(style, Attributes::ITALIC),
+            Some(24) => remove(
+                style,
+                Attributes::UNDERLINED | Attributes::DOUBLE_UNDERLINED,
+            ),
+            Some(25) => remove(style, Attributes::BLINKING | Attributes::RAPID_BLINKING),
+            Some(27) => remove(style, Attributes::INVERTED),
+            Some(28) => remove(style, Attributes::HIDDEN),
+            Some(29) => remove(style, Attributes::CROSSED),
+            Some(54) => remove(style, Attributes::FRAMED | Attributes::ENCIRCLED),
+            Some(55) => remove(style, Attributes::OVERLINED),
+            Some(75) => remove(style, Attributes::SUPERSCRIPT | Attributes::SUBSCRIPT),
+
+            Some(code @ 30..=37) => style.fg = Color::Ansi(AnsiColor::from_index(code as u8 - 30)),
+            Some(code @ 90..=97) => style.fg = Color::Ansi(AnsiColor::from_index(code as u8 - 82)),
+            Some(code @ 40..=47) => style.bg = Color::Ansi(AnsiColor::from_index(code as u8 - 40)),
+            Some(code @ 100..=107) => {
+                style.bg = Color::Ansi(AnsiColor::from_index(code as u8 - 92))
+            }
+
+            Some(39) => style.fg = Color::Default,
+            Some(49) => style.bg = Color::Default,
+            Some(59) => style.underline_color = Color::Default,
+
+            Some(code @ (38 | 48 | 58)) => {
+                // Extended colors are either separated by colons within the parameter,
+                // or by semicolons as the following parameters.
+                let color = if param.contains(':') {
+                    parse_extended_color(&mut subparams, true)
+                } else {
+                    parse_extended_color(&mut params, false)
+                };
+
+                match color {
+                    Some(color) => match code {
+                        38 => style.fg = color,
+                        48 => style.bg = color,
+                        _ => style.underline_color = color,
+                    },
+                    None => known = false,
+                }
+            }
+
+            _ => known = false,
+        }
+    }
+
+    known
+}
+
+/// Parse the rest of an extended color, after `38`, `48` or `58`.
+fn parse_extended_color<'a>(
+    params: &mut impl Iterator<Item = &'a str>,
+    colons: bool,
+) -> Option<Color> {
+    let mut next = || params.next().map(parse_param);
+
+    match next()?? {
+        5 => Some(Color::Ansi256(next()??.try_into().ok()?)),
+
+        2 => {
+            // With colons, the components may be preceded by a color space ID, which is
+            // usually empty.
+            let mut components = [None; 4];
+            let mut len = 0;
+            for component in &mut components[..if colons { 4 } else { 3 }] {
+                match next() {
+                    Some(value) => *component = value,
+                    None => break,
+                }
+                len += 1;
+            }
+
+            let [r, g, b] = match len {
+                4 => [components[1], components[2], components[3]],
+                3 => [components[0], components[1], components[2]],
+                _ => return None,
+            };
+
+            Some(Color::Rgb(
+                r?.try_into().ok()?,
+                g?.try_into().ok()?,
+                b?.try_into().ok()?,
+            ))
+        }
+
+        _ => None,
+    }
+}
+
+fn parse_param(param: &str) -> Option<u32> {
+    if param.is_empty() {
+        return None;
+    }
+
+    // Overly long numbers are invalid anyway, so saturate rather than failing.
+    Some(param.bytes().fold(0u32, |n, b| {
+        n.saturating_mul(10).saturating_add((b - b'0') as u32)
+    }))
+}
@@ -38,10 +38,59 @@ pub enum AnsiColor {
     BrightWhite,
 }
 
+impl AnsiColor {
+    /// Returns the color with the given index in the 4-bit palette, ignoring any higher bits.
+    pub(crate) const fn from_index(i: u8) -> Self {
+        match i & 0xf {
+            0 => Self::Black,
+            1 => Self::Red,
+            2 => Self::Green,
+            3 => Self::Yellow,
+            4 => Self::Blue,
+            5 => Self::Magenta,
+            6 => Self::Cyan,
+            7 => Self::White,
+            8 => Self::BrightBlack,
+            9 => Self::BrightRed,
+            10 => Self::BrightGreen,
+            11 => Self::BrightYellow,
+            12 => Self::BrightBlue,
+            13 => Self::BrightMagenta,
+            14 => Self::BrightCyan,
+            _ => Self::BrightWhite,
+        }
+    }
+}
+
+// Generates a single builder method. The receiver is kept separate from the other
+// parameters since it comes from the caller.
+macro_rules! builder_method {
+    (
+        $(#[$meta:meta])*
+        [$($qualifiers:tt)*] fn $name:ident ($($receiver:tt)*) ($($param:ident: $param_ty:ty),*) -> $output:ty
+        $body:block
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        $($qualifiers)* fn $name($($receiver)*, $($param: $param_ty),*) -> $output
+        $body
+    };
+}
+
+// The methods are generated with the given qualifiers (such as `[pub const]`), receiver
+// (such as `(mut self: Self)`) and return type, so that they can be shared between the
+// style types and the `Stylize` trait.
 macro_rules! impl_color_builder_methods {
     ($self:ident, $color:ident => $output_fg:expr, $output_bg:expr) => {
         impl_color_builder_methods! {
-            @ $self, $color => $output_fg, $output_bg;
+            [pub const] (mut $self: Self) -> Self;
+            $color => $output_fg, $output_bg
+        }
+    };
+
+    ($qualifiers:tt $receiver:tt -> $output:ty; $color:ident => $output_fg:expr, $output_bg:expr) => {
+        impl_color_builder_methods! {
+            @ $qualifiers $receiver -> $output; $color => $output_fg, $output_bg;
 
             colors(
                 Black
@@ -138,83 +187,93 @@ macro_rules! impl_color_builder_methods {
     };
 
     (
-        @ $self:ident, $color:ident => $output_fg:expr, $output_bg:expr;
+        @ $qualifiers:tt $receiver:tt -> $output:ty; $color:ident => $output_fg:expr, $output_bg:expr;
         colors($($variant:ident)*)
         fg($($(#[$fg_meta:meta])* $fg_name:ident)*)
         bg($($(#[$bg_meta:meta])* $bg_name:ident)*)
     ) => {
-        /// Set the foreground color.
-        #[inline]
-        pub const fn fg(mut $self: Self, $color: $crate::Color) -> Self {
-            $output_fg
+        $crate::color::builder_method! {
+            /// Set the foreground color.
+            $qualifiers fn fg $receiver ($color: $crate::Color) -> $output {
+                $output_fg
+            }
         }
 
-        /// Set the background color.
-        #[inline]
-        pub const fn bg(mut $self: Self, $color: $crate::Color) -> Self {
-            $output_bg
+        $crate::color::builder_method! {
+            /// Set the background color.
+            $qualifiers fn bg $receiver ($color: $crate::Color) -> $output {
+                $output_bg
+            }
         }
 
-        /// Set the foreground color to an RGB color.
-        #[inline]
-        pub const fn rgb_color(mut $self: Self, r: u8, g: u8, b: u8) -> Self {
-            let $color = $crate::Color::Rgb(r, g, b);
-            $output_fg
+        $crate::color::builder_method! {
+            /// Set the foreground color to an RGB color.
+            $qualifiers fn rgb_color $receiver (r: u8, g: u8, b: u8) -> $output {
+                let $color = $crate::Color::Rgb(r, g, b);
+                $output_fg
+            }
         }
 
-        /// Set the foreground color to an ANSI 4-bit color.
-        #[inline]
-        pub const fn ansi_color(mut $self: Self, color: $crate::AnsiColor) -> Self {
-            let $color = $crate::Color::Ansi(color);
-            $output_fg
+        $crate::color::builder_method! {
+            /// Set the foreground color to an ANSI 4-bit color.
+            $qualifiers fn ansi_color $receiver (color: $crate::AnsiColor) -> $output {
+                let $color = $crate::Color::Ansi(color);
+                $output_fg
+            }
         }
 
-        /// Set the foreground color to an ANSI 8-bit color.
-        #[inline]
-        pub const fn ansi256_color(mut $self: Self, i: u8) -> Self {
-            let $color = $crate::Color::Ansi256(i);
-            $output_fg
+        $crate::color::builder_method! {
+            /// Set the foreground color to an ANSI 8-bit color.
+            $qualifiers fn ansi256_color $receiver (i: u8) -> $output {
+                let $color = $crate::Color::Ansi256(i);
+                $output_fg
+            }
         }
 
-        /// Set the background color to an RGB color.
-        #[inline]
-        pub const fn on_color_rgb(mut $self: Self, r: u8, g: u8, b: u8) -> Self {
-            let $color = $crate::Color::Rgb(r, g, b);
-            $output_bg
+        $crate::color::builder_method! {
+            /// Set the background color to an RGB color.
+            $qualifiers fn on_color_rgb $receiver (r: u8, g: u8, b: u8) -> $output {
+                let $color = $crate::Color::Rgb(r, g, b);
+                $output_bg
+            }
         }
 
-        /// Set the background color to an ANSI 4-bit color.
-        #[inline]
-        pub const fn on_color_ansi(mut $self: Self, color: $crate::AnsiColor) -> Self {
-            let $color = $crate::Color::Ansi(color);
-            $output_bg
+        $crate::color::builder_method! {
+            /// Set the background color to an ANSI 4-bit color.
+            $qualifiers fn on_color_ansi $receiver (color: $crate::AnsiColor) -> $output {
+                let $color = $crate::Color::Ansi(color);
+                $output_bg
+            }
         }
 
-        /// Set the background color to an ANSI 8-bit color.
-        #[inline]
-        pub const fn on_color_ansi256(mut $self: Self, i: u8) -> Self {
-            let $color = $crate::Color::Ansi256(i);
-            $output_bg
+        $crate::color::builder_method! {
+            /// Set the background color to an ANSI 8-bit color.
+            $qualifiers fn on_color_ansi256 $receiver (i: u8) -> $output {
+                let $color = $crate::Color::Ansi256(i);
+                $output_bg
+            }
         }
 
         $(
-            #[inline]
-            $(#[$fg_meta])*
-            pub const fn $fg_name(mut $self: Self) -> Self {
-                let $color = $crate::Color::Ansi($crate::AnsiColor::$variant);
-                $output_fg
+            $crate::color::builder_method! {
+                $(#[$fg_meta])*
+                $qualifiers fn $fg_name $receiver () -> $output {
+                    let $color = $crate::Color::Ansi($crate::AnsiColor::$variant);
+                    $output_fg
+                }
             }
         )*
 
         $(
-            #[inline]
-            $(#[$bg_meta])*
-            pub const fn $bg_name(mut $self: Self) -> Self {
-                let $color = $crate::Color::Ansi($crate::AnsiColor::$variant);
-                $output_bg
+            $crate::color::builder_method! {
+                $(#[$bg_meta])*
+                $qualifiers fn $bg_name $receiver () -> $output {
+                    let $color = $crate::Color::Ansi($crate::AnsiColor::$variant);
+                    $output_bg
+                }
             }
         )*
     };
 }
 
-pub(crate) use impl_color_builder_methods;
+pub(crate) use {builder_method, impl_color_builder_methods};
@@ -1,402 +1,212 @@
-use core::{
-    fmt::{self, Display},
-    str,
-};
+use core::fmt;
 
-use crate::style::{Attributes, Style, Styled};
-use crate::{AnsiColor, Color, Hyperlink};
+use crate::options::{Content, Options};
+use crate::sgr::{transition_sgr, Sgr};
+use crate::style::{AsDebug, ForStream, Inherit, StaticStyled, Style, StyleTransition, Styled};
+use crate::{ColorLevel, Hyperlink};
 
 #[cfg(feature = "nested_styles")]
-use core::cell::Cell;
+use crate::nesting;
+
+// The content is formatted using the same trait, so for example `{:x}` formats a
+// styled number in hexadecimal. Each impl passes the content to `write_styled` as a
+// closure, which is inlined, so this costs nothing over calling the trait directly.
+macro_rules! impl_fmt_traits {
+    ($($trait:ident)*) => {
+        $(
+            impl<T: fmt::$trait> fmt::$trait for Styled<T> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match crate::current_color_level() {
+                        ColorLevel::None => fmt::$trait::fmt(&self.content, f),
+                        level => write_styled(
+                            f,
+                            self.style,
+                            &style_sgr(self.style, false, level),
+                            false,
+                            level,
+                            |f| fmt::$trait::fmt(&self.content, f),
+                        ),
+                    }
+                }
+            }
 
-#[cfg(feature = "nested_styles")]
-thread_local! {
-    static RESET_STYLE: Cell<Style> = const { Cell::new(Style::new()) };
+            impl<T: fmt::$trait> fmt::$trait for Inherit<Styled<T>> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let value = &self.value;
+
+                    match crate::current_color_level() {
+                        ColorLevel::None => fmt::$trait::fmt(&value.content, f),
+                        level => write_styled(
+                            f,
+                            value.style,
+                            &style_sgr(value.style, true, level),
+                            true,
+                            level,
+                            |f| fmt::$trait::fmt(&value.content, f),
+                        ),
+                    }
+                }
+            }
+
+            impl<T: fmt::$trait> fmt::$trait for StaticStyled<T> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match crate::current_color_level() {
+                        ColorLevel::None => fmt::$trait::fmt(&self.content, f),
+                        level => write_styled(
+                            f,
+                            self.style.style(),
+                            self.style.sgr_at(level),
+                            false,
+                            level,
+                            |f| fmt::$trait::fmt(&self.content, f),
+                        ),
+                    }
+                }
+            }
+
+            impl<T: fmt::$trait> fmt::$trait for ForStream<T> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    crate::enable::with_render_stream(self.stream, || fmt::$trait::fmt(&self.value, f))
+                }
+            }
+        )*
+    };
 }
 
-impl<T: fmt::Display> fmt::Display for Styled<T> {
+impl_fmt_traits!(Display LowerHex UpperHex Octal Binary LowerExp UpperExp Pointer);
+
+impl<T: fmt::Debug> fmt::Display for AsDebug<T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if crate::is_style_enabled() {
-            write_styled(f, self.style, &self.content)
-        } else {
-            Ok(())
-        }
+        self.0.fmt(f)
     }
 }
 
 impl fmt::Display for Style {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if !crate::is_style_enabled() {
-            return Ok(());
+        match crate::current_color_level() {
+            ColorLevel::None => Ok(()),
+            level => write_style(f, *self, level),
         }
-
-        write_style(f, *self)
     }
 }
 
 impl<T: fmt::Display, L: fmt::Display> fmt::Display for Hyperlink<T, L> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if !crate::is_style_enabled() {
-            return self.content.fmt(f);
-        }
-
-        f.write_str("\x1b]8;;")?;
-        self.uri.fmt(f)?;
-        f.write_str("\x1b\\")?;
-
-        write_styled(f, self.style, &self.content)?;
-
-        f.write_str("\x1b]8;;\x1b\\")?;
-
-        Ok(())
+        write_hyperlink(f, self, false)
     }
 }
 
-#[cfg(not(feature = "nested_styles"))]
-#[inline]
-fn write_styled(f: &mut fmt::Formatter, style: Style, content: &impl fmt::Display) -> fmt::Result {
-    style.fmt(f)?;
-    content.fmt(f)?;
-    f.write_str("\x1b[0")
+impl<T: fmt::Display, L: fmt::Display> fmt::Display for Inherit<Hyperlink<T, L>> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hyperlink(f, &self.value, true)
+    }
 }
 
-#[cfg(feature = "nested_styles")]
-#[inline]
-fn write_styled(f: &mut fmt::Formatter, style: Style, content: &impl fmt::Display) -> fmt::Result {
-    let reset_style = RESET_STYLE.get();
+fn write_hyperlink<T: fmt::Display, L: fmt::Display>(
+    f: &mut fmt::Formatter,
+    hyperlink: &Hyperlink<T, L>,
+    inherit: bool,
+) -> fmt::Result {
+    let level = match crate::current_color_level() {
+        ColorLevel::None => return hyperlink.content.fmt(f),
+        level => level,
+    };
 
-    RESET_STYLE.set(style);
+    // The formatter's options are only meant for the content.
+    f.write_fmt(format_args!("\x1b]8;;{}\x1b\\", hyperlink.uri))?;
 
-    style.fmt(f)?;
-    content.fmt(f)?;
-    reset_style.fmt(f)?;
+    let sgr = style_sgr(hyperlink.style, inherit, level);
+    write_styled(f, hyperlink.style, &sgr, inherit, level, |f| {
+        hyperlink.content.fmt(f)
+    })?;
 
-    RESET_STYLE.set(reset_style);
+    f.write_str("\x1b]8;;\x1b\\")?;
 
     Ok(())
 }
 
-#[inline]
-fn write_style(f: &mut fmt::Formatter, style: Style) -> fmt::Result {
-    f.write_str("\x1b[0")?;
-
-    write_fg_color(f, style.fg)?;
-    write_bg_color(f, style.bg)?;
-
-    write_attributes(f, style.attributes)?;
-
-    f.write_str("m")
+impl fmt::Display for StyleTransition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match crate::current_color_level() {
+            ColorLevel::None => Ok(()),
+            level => write_transition(f, self.from, self.to, level),
+        }
+    }
 }
 
-// Separate functions for formatting colors shows performance improvement.
-macro_rules! impl_write_color {
-    ($name:ident $prefix:literal $bright_prefix:literal) => {
-        #[inline]
-        fn $name(f: &mut fmt::Formatter, color: Color) -> fmt::Result {
-            match color {
-                Color::Default => Ok(()),
-
-                Color::Ansi(color) => match color {
-                    AnsiColor::Black => f.write_str(concat!(";", $prefix, "0")),
-                    AnsiColor::Red => f.write_str(concat!(";", $prefix, "1")),
-                    AnsiColor::Green => f.write_str(concat!(";", $prefix, "2")),
-                    AnsiColor::Yellow => f.write_str(concat!(";", $prefix, "3")),
-                    AnsiColor::Blue => f.write_str(concat!(";", $prefix, "4")),
-                    AnsiColor::Magenta => f.write_str(concat!(";", $prefix, "5")),
-                    AnsiColor::Cyan => f.write_str(concat!(";", $prefix, "6")),
-                    AnsiColor::White => f.write_str(concat!(";", $prefix, "7")),
-
-                    AnsiColor::BrightBlack => f.write_str(concat!(";", $bright_prefix, "0")),
-                    AnsiColor::BrightRed => f.write_str(concat!(";", $bright_prefix, "1")),
-                    AnsiColor::BrightGreen => f.write_str(concat!(";", $bright_prefix, "2")),
-                    AnsiColor::BrightYellow => f.write_str(concat!(";", $bright_prefix, "3")),
-                    AnsiColor::BrightBlue => f.write_str(concat!(";", $bright_prefix, "4")),
-                    AnsiColor::BrightMagenta => f.write_str(concat!(";", $bright_prefix, "5")),
-                    AnsiColor::BrightCyan => f.write_str(concat!(";", $bright_prefix, "6")),
-                    AnsiColor::BrightWhite => f.write_str(concat!(";", $bright_prefix, "7")),
-                },
+// The escape sequence that opens a styled value. A value that inherits only changes what
+// its style sets, without a reset first. Without `nested_styles` the enclosing style isn't
+// known, so there is nothing to inherit.
+#[inline]
+fn style_sgr(style: Style, inherit: bool, level: ColorLevel) -> Sgr {
+    if inherit && cfg!(feature = "nested_styles") {
+        transition_sgr(Style::new(), style, level)
+    } else {
+        style.sgr_at(level)
+    }
+}
 
-                Color::Ansi256(i) => {
-                    f.write_str(concat!(";", $prefix, "8;5;"))?;
-                    i.fmt(f)
-                }
+// The content pads itself, unless it is padded here to measure it by visible width.
+#[cfg(not(feature = "nested_styles"))]
+#[inline]
+fn write_styled(
+    f: &mut fmt::Formatter,
+    _style: Style,
+    sgr: &str,
+    _inherit: bool,
+    _level: ColorLevel,
+    content: impl Fn(&mut fmt::Formatter) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str(sgr)?;
+
+    if f.width().is_some() {
+        Options::of(f).write(f, &Content(content))?;
+    } else {
+        content(f)?;
+    }
 
-                Color::Rgb(r, g, b) => {
-                    f.write_str(concat!(";", $prefix, "8;2;"))?;
-                    r.fmt(f)?;
-                    f.write_str(";")?;
-                    g.fmt(f)?;
-                    f.write_str(";")?;
-                    b.fmt(f)
-                }
-            }
-        }
-    };
+    f.write_str("\x1b[0m")
 }
 
-impl_write_color!(write_fg_color "3" "9");
-impl_write_color!(write_bg_color "4" "10");
+// The content is written through a writer that restores the style after any reset in
+// it, so the style of the enclosing value is restored at the end of a nested value.
+#[cfg(feature = "nested_styles")]
+#[inline]
+fn write_styled(
+    f: &mut fmt::Formatter,
+    style: Style,
+    sgr: &str,
+    inherit: bool,
+    level: ColorLevel,
+    content: impl Fn(&mut fmt::Formatter) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str(sgr)?;
+
+    let options = Options::of(f);
+    let mut writer = nesting::Nested::new(f, style, sgr, inherit, level);
+    options.write(&mut writer, &Content(content))?;
+    writer.finish()?;
+
+    f.write_str("\x1b[0m")
+}
 
-fn write_attributes(f: &mut fmt::Formatter, attributes: Attributes) -> fmt::Result {
-    f.write_str(ATTRIBUTE_LOOKUP[attributes.into_bits() as usize])
+// The whole sequence is generated on the stack up front, so that it can be written
+// at once.
+#[inline]
+fn write_style(f: &mut fmt::Formatter, style: Style, level: ColorLevel) -> fmt::Result {
+    f.write_str(style.sgr_at(level).as_str())
 }
 
-// Since the attributes are only 8 bits, we can use a lookup table.
-const ATTRIBUTE_LOOKUP: [&str; 256] = [
-    "",
-    ";1",
-    ";2",
-    ";2;1",
-    ";3",
-    ";3;1",
-    ";3;2",
-    ";3;2;1",
-    ";4",
-    ";4;1",
-    ";4;2",
-    ";4;2;1",
-    ";4;3",
-    ";4;3;1",
-    ";4;3;2",
-    ";4;3;2;1",
-    ";5",
-    ";5;1",
-    ";5;2",
-    ";5;2;1",
-    ";5;3",
-    ";5;3;1",
-    ";5;3;2",
-    ";5;3;2;1",
-    ";5;4",
-    ";5;4;1",
-    ";5;4;2",
-    ";5;4;2;1",
-    ";5;4;3",
-    ";5;4;3;1",
-    ";5;4;3;2",
-    ";5;4;3;2;1",
-    ";7",
-    ";7;1",
-    ";7;2",
-    ";7;2;1",
-    ";7;3",
-    ";7;3;1",
-    ";7;3;2",
-    ";7;3;2;1",
-    ";7;4",
-    ";7;4;1",
-    ";7;4;2",
-    ";7;4;2;1",
-    ";7;4;3",
-    ";7;4;3;1",
-    ";7;4;3;2",
-    ";7;4;3;2;1",
-    ";7;5",
-    ";7;5;1",
-    ";7;5;2",
-    ";7;5;2;1",
-    ";7;5;3",
-    ";7;5;3;1",
-    ";7;5;3;2",
-    ";7;5;3;2;1",
-    ";7;5;4",
-    ";7;5;4;1",
-    ";7;5;4;2",
-    ";7;5;4;2;1",
-    ";7;5;4;3",
-    ";7;5;4;3;1",
-    ";7;5;4;3;2",
-    ";7;5;4;3;2;1",
-    ";8",
-    ";8;1",
-    ";8;2",
-    ";8;2;1",
-    ";8;3",
-    ";8;3;1",
-    ";8;3;2",
-    ";8;3;2;1",
-    ";8;4",
-    ";8;4;1",
-    ";8;4;2",
-    ";8;4;2;1",
-    ";8;4;3",
-    ";8;4;3;1",
-    ";8;4;3;2",
-    ";8;4;3;2;1",
-    ";8;5",
-    ";8;5;1",
-    ";8;5;2",
-    ";8;5;2;1",
-    ";8;5;3",
-    ";8;5;3;1",
-    ";8;5;3;2",
-    ";8;5;3;2;1",
-    ";8;5;4",
-    ";8;5;4;1",
-    ";8;5;4;2",
-    ";8;5;4;2;1",
-    ";8;5;4;3",
-    ";8;5;4;3;1",
-    ";8;5;4;3;2",
-    ";8;5;4;3;2;1",
-    ";8;7",
-    ";8;7;1",
-    ";8;7;2",
-    ";8;7;2;1",
-    ";8;7;3",
-    ";8;7;3;1",
-    ";8;7;3;2",
-    ";8;7;3;2;1",
-    ";8;7;4",
-    ";8;7;4;1",
-    ";8;7;4;2",
-    ";8;7;4;2;1",
-    ";8;7;4;3",
-    ";8;7;4;3;1",
-    ";8;7;4;3;2",
-    ";8;7;4;3;2;1",
-    ";8;7;5",
-    ";8;7;5;1",
-    ";8;7;5;2",
-    ";8;7;5;2;1",
-    ";8;7;5;3",
-    ";8;7;5;3;1",
-    ";8;7;5;3;2",
-    ";8;7;5;3;2;1",
-    ";8;7;5;4",
-    ";8;7;5;4;1",
-    ";8;7;5;4;2",
-    ";8;7;5;4;2;1",
-    ";8;7;5;4;3",
-    ";8;7;5;4;3;1",
-    ";8;7;5;4;3;2",
-    ";8;7;5;4;3;2;1",
-    ";9",
-    ";9;1",
-    ";9;2",
-    ";9;2;1",
-    ";9;3",
-    ";9;3;1",
-    ";9;3;2",
-    ";9;3;2;1",
-    ";9;4",
-    ";9;4;1",
-    ";9;4;2",
-    ";9;4;2;1",
-    ";9;4;3",
-    ";9;4;3;1",
-    ";9;4;3;2",
-    ";9;4;3;2;1",
-    ";9;5",
-    ";9;5;1",
-    ";9;5;2",
-    ";9;5;2;1",
-    ";9;5;3",
-    ";9;5;3;1",
-    ";9;5;3;2",
-    ";9;5;3;2;1",
-    ";9;5;4",
-    ";9;5;4;1",
-    ";9;5;4;2",
-    ";9;5;4;2;1",
-    ";9;5;4;3",
-    ";9;5;4;3;1",
-    ";9;5;4;3;2",
-    ";9;5;4;3;2;1",
-    ";9;7",
-    ";9;7;1",
-    ";9;7;2",
-    ";9;7;2;1",
-    ";9;7;3",
-    ";9;7;3;1",
-    ";9;7;3;2",
-    ";9;7;3;2;1",
-    ";9;7;4",
-    ";9;7;4;1",
-    ";9;7;4;2",
-    ";9;7;4;2;1",
-    ";9;7;4;3",
-    ";9;7;4;3;1",
-    ";9;7;4;3;2",
-    ";9;7;4;3;2;1",
-    ";9;7;5",
-    ";9;7;5;1",
-    ";9;7;5;2",
-    ";9;7;5;2;1",
-    ";9;7;5;3",
-    ";9;7;5;3;1",
-    ";9;7;5;3;2",
-    ";9;7;5;3;2;1",
-    ";9;7;5;4",
-    ";9;7;5;4;1",
-    ";9;7;5;4;2",
-    ";9;7;5;4;2;1",
-    ";9;7;5;4;3",
-    ";9;7;5;4;3;1",
-    ";9;7;5;4;3;2",
-    ";9;7;5;4;3;2;1",
-    ";9;8",
-    ";9;8;1",
-    ";9;8;2",
-    ";9;8;2;1",
-    ";9;8;3",
-    ";9;8;3;1",
-    ";9;8;3;2",
-    ";9;8;3;2;1",
-    ";9;8;4",
-    ";9;8;4;1",
-    ";9;8;4;2",
-    ";9;8;4;2;1",
-    ";9;8;4;3",
-    ";9;8;4;3;1",
-    ";9;8;4;3;2",
-    ";9;8;4;3;2;1",
-    ";9;8;5",
-    ";9;8;5;1",
-    ";9;8;5;2",
-    ";9;8;5;2;1",
-    ";9;8;5;3",
-    ";9;8;5;3;1",
-    ";9;8;5;3;2",
-    ";9;8;5;3;2;1",
-    ";9;8;5;4",
-    ";9;8;5;4;1",
-    ";9;8;5;4;2",
-    ";9;8;5;4;2;1",
-    ";9;8;5;4;3",
-    ";9;8;5;4;3;1",
-    ";9;8;5;4;3;2",
-    ";9;8;5;4;3;2;1",
-    ";9;8;7",
-    ";9;8;7;1",
-    ";9;8;7;2",
-    ";9;8;7;2;1",
-    ";9;8;7;3",
-    ";9;8;7;3;1",
-    ";9;8;7;3;2",
-    ";9;8;7;3;2;1",
-    ";9;8;7;4",
-    ";9;8;7;4;1",
-    ";9;8;7;4;2",
-    ";9;8;7;4;2;1",
-    ";9;8;7;4;3",
-    ";9;8;7;4;3;1",
-    ";9;8;7;4;3;2",
-    ";9;8;7;4;3;2;1",
-    ";9;8;7;5",
-    ";9;8;7;5;1",
-    ";9;8;7;5;2",
-    ";9;8;7;5;2;1",
-    ";9;8;7;5;3",
-    ";9;8;7;5;3;1",
-    ";9;8;7;5;3;2",
-    ";9;8;7;5;3;2;1",
-    ";9;8;7;5;4",
-    ";9;8;7;5;4;1",
-    ";9;8;7;5;4;2",
-    ";9;8;7;5;4;2;1",
-    ";9;8;7;5;4;3",
-    ";9;8;7;5;4;3;1",
-    ";9;8;7;5;4;3;2",
-    ";9;8;7;5;4;3;2;1",
-];
+#[inline]
+fn write_transition(
+    f: &mut fmt::Formatter,
+    from: Style,
+    to: Style,
+    level: ColorLevel,
+) -> fmt::Result {
+    f.write_str(transition_sgr(from, to, level).as_str())
+}
@@ -1,29 +1,305 @@
 use core::sync::atomic::{AtomicU8, Ordering};
 
-static STYLING_ENABLED: AtomicU8 = AtomicU8::new(0);
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+// The style mode of each stream, see `StyleMode::from_bits`.
+static STYLE_MODES: [AtomicU8; 3] = [AtomicU8::new(0), AtomicU8::new(0), AtomicU8::new(0)];
+
+// For each stream, 0 means the color level has not been auto-detected yet,
+// otherwise this stores the detected color level plus one.
+static DETECTED_LEVELS: [AtomicU8; 3] = [AtomicU8::new(0), AtomicU8::new(0), AtomicU8::new(0)];
+
+#[cfg(feature = "std")]
+thread_local! {
+    // A style mode that overrides the style mode of every stream on this thread.
+    static STYLE_MODE_OVERRIDE: Cell<Option<StyleMode>> = const { Cell::new(None) };
+
+    // The stream that styled values are currently being rendered for.
+    static RENDER_STREAM: Cell<Stream> = const { Cell::new(Stream::Stdout) };
+}
+
+// The stream that styled values are currently being rendered for.
+//
+// Without `std` there are no thread locals, so this is shared by the whole program:
+// rendering for different streams at the same time (for example from an interrupt
+// handler) may use the wrong stream's style mode.
+#[cfg(not(feature = "std"))]
+static RENDER_STREAM: AtomicU8 = AtomicU8::new(Stream::Stdout as u8);
 
-/// Set the style mode.
+/// Set the style mode for all streams.
 ///
-/// Returns `true` if styling was enabled.
+/// Returns `true` if styling was enabled for standard output.
 ///
 /// By default, the style mode is [`StyleMode::Auto`], so unless the style mode
 /// has been changed, there is no need to call `set_style_mode(StyleMode::Auto)`.
+///
+/// Use [`Stream::set_style_mode`] to set the style mode of a single stream.
 pub fn set_style_mode(mode: StyleMode) -> bool {
-    let enable_styling = mode.should_enable_styling();
-    STYLING_ENABLED.store(if enable_styling { 2 } else { 1 }, Ordering::Relaxed);
-    enable_styling
+    Stream::Stderr.set_style_mode(mode);
+    Stream::Custom.set_style_mode(mode);
+    Stream::Stdout.set_style_mode(mode)
+}
+
+/// Returns the effective style mode of the stream that is currently being rendered
+/// for (standard output by default).
+///
+/// This takes into account any override set on this thread with [`with_style_mode`].
+pub fn get_style_mode() -> StyleMode {
+    render_stream().style_mode()
+}
+
+/// Override the style mode of every stream for the current thread only, for the
+/// duration of the closure.
+///
+/// The previous style mode is restored afterwards, even if the closure panics.
+/// This is useful for tests, which usually run in parallel on multiple threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{get_style_mode, is_style_enabled, with_style_mode, StyleMode};
+///
+/// with_style_mode(StyleMode::disable(), || {
+///     assert!(!is_style_enabled());
+///     assert_eq!(get_style_mode(), StyleMode::disable());
+/// });
+/// ```
+#[cfg(feature = "std")]
+pub fn with_style_mode<R>(mode: StyleMode, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<StyleMode>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STYLE_MODE_OVERRIDE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(STYLE_MODE_OVERRIDE.replace(Some(mode)));
+    f()
 }
 
 /// Returns `true` if styling is enabled.
+///
+/// This is the same as checking that [`current_color_level`] is not [`ColorLevel::None`].
 pub fn is_style_enabled() -> bool {
-    match STYLING_ENABLED.load(Ordering::Relaxed) {
-        // lazy initialization
-        0 => set_style_mode(StyleMode::Auto),
+    current_color_level() != ColorLevel::None
+}
+
+/// Returns the color level that styled values are currently rendered with.
+///
+/// This is the color level of the stream that is being rendered for (see
+/// [`Styled::for_stream`](crate::Styled::for_stream)), which is standard output by default.
+pub fn current_color_level() -> ColorLevel {
+    render_stream().color_level()
+}
+
+#[cfg(feature = "std")]
+fn render_stream() -> Stream {
+    RENDER_STREAM.get()
+}
+
+#[cfg(not(feature = "std"))]
+fn render_stream() -> Stream {
+    Stream::from_bits(RENDER_STREAM.load(Ordering::Relaxed))
+}
+
+#[cfg(feature = "std")]
+fn set_render_stream(stream: Stream) -> Stream {
+    RENDER_STREAM.replace(stream)
+}
+
+#[cfg(not(feature = "std"))]
+fn set_render_stream(stream: Stream) -> Stream {
+    // Only loads and stores are used, since some targets don't support swapping atomically.
+    let previous = render_stream();
+    RENDER_STREAM.store(stream as u8, Ordering::Relaxed);
+    previous
+}
+
+/// Render for the given stream for the duration of the closure.
+///
+/// The previous stream is restored afterwards, even if the closure panics.
+pub(crate) fn with_render_stream<R>(stream: Stream, f: impl FnOnce() -> R) -> R {
+    struct Restore(Stream);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_render_stream(self.0);
+        }
+    }
+
+    let _restore = Restore(set_render_stream(stream));
+    f()
+}
+
+/// An output stream, which has its own style mode.
+///
+/// Styled values are rendered for standard output unless another stream is
+/// chosen with [`Styled::for_stream`](crate::Styled::for_stream).
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{styled, Stream, StyleMode};
+///
+/// Stream::Stdout.set_style_mode(StyleMode::disable());
+/// Stream::Stderr.set_style_mode(StyleMode::enable());
+///
+/// // Styling will only be applied to the message on stderr.
+/// println!("{}", styled!("Hello").red());
+/// eprintln!("{}", styled!("Hello").red().for_stream(Stream::Stderr));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stream {
+    /// Standard output.
+    #[default]
+    Stdout,
+
+    /// Standard error.
+    Stderr,
+
+    /// Any other output, such as a file or an in-memory buffer.
+    ///
+    /// Since it is unknown where this output ends up, it is never detected as
+    /// a terminal, so auto-detection only enables styling if it is forced by
+    /// the environment.
+    Custom,
+}
+
+impl Stream {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    const fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => Self::Stdout,
+            1 => Self::Stderr,
+            2 => Self::Custom,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Set the style mode of this stream.
+    ///
+    /// Returns `true` if styling was enabled.
+    ///
+    /// Setting the style mode to [`StyleMode::Auto`] detects the color level again.
+    pub fn set_style_mode(self, mode: StyleMode) -> bool {
+        if mode == StyleMode::Auto {
+            DETECTED_LEVELS[self as usize].store(0, Ordering::Relaxed);
+        }
+
+        STYLE_MODES[self as usize].store(mode.into_bits(), Ordering::Relaxed);
+
+        self.resolve(mode) != ColorLevel::None
+    }
+
+    /// Returns the effective style mode of this stream.
+    ///
+    /// This takes into account any override set on this thread with [`with_style_mode`].
+    pub fn style_mode(self) -> StyleMode {
+        #[cfg(feature = "std")]
+        if let Some(mode) = STYLE_MODE_OVERRIDE.get() {
+            return mode;
+        }
+
+        StyleMode::from_bits(STYLE_MODES[self as usize].load(Ordering::Relaxed))
+    }
+
+    /// Returns the color level of this stream.
+    pub fn color_level(self) -> ColorLevel {
+        self.resolve(self.style_mode())
+    }
+
+    fn resolve(self, mode: StyleMode) -> ColorLevel {
+        match mode {
+            StyleMode::Force(level) => level,
+            StyleMode::Auto => self.detected_level(),
+        }
+    }
+
+    fn detected_level(self) -> ColorLevel {
+        match DETECTED_LEVELS[self as usize].load(Ordering::Relaxed) {
+            // lazy initialization
+            0 => {
+                let level = env_color_level(self);
+                DETECTED_LEVELS[self as usize].store(level as u8 + 1, Ordering::Relaxed);
+                level
+            }
+
+            n => ColorLevel::from_bits(n - 1),
+        }
+    }
+
+    /// Returns `true` if styling is enabled for this stream.
+    #[inline]
+    pub fn is_style_enabled(self) -> bool {
+        self.color_level() != ColorLevel::None
+    }
+}
+
+/// The level of styling supported by a terminal.
+///
+/// Levels are ordered, so a level supports everything that the levels below it support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorLevel {
+    /// No styling at all.
+    None,
+
+    /// Attributes (for example, bold or italic) but no colors.
+    Attributes,
+
+    /// The 16 ANSI 4-bit colors.
+    Ansi,
+
+    /// The ANSI 8-bit color palette.
+    Ansi256,
+
+    /// RGB colors (also known as truecolor).
+    Rgb,
+}
+
+impl ColorLevel {
+    #[inline]
+    const fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => Self::None,
+            1 => Self::Attributes,
+            2 => Self::Ansi,
+            3 => Self::Ansi256,
+            4 => Self::Rgb,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns `true` if colors are supported at this level.
+    #[inline]
+    pub const fn supports_color(self) -> bool {
+        self as u8 >= Self::Ansi as u8
+    }
+
+    /// Detect the color level from the environment, for an output that is or isn't
+    /// a terminal.
+    ///
+    /// This follows the rules described in [`StyleMode::auto`], and is useful for
+    /// outputs other than standard output and standard error, such as a pseudoterminal.
+    #[cfg(feature = "std")]
+    pub fn detect(is_terminal: bool) -> Self {
+        use std::env::var_os;
 
-        1 => false,
-        2 => true,
+        if let Some(level) = forced_color_level() {
+            return level;
+        }
+
+        if var_os("CLICOLOR").is_some_and(|s| s == "0") || !is_terminal {
+            return ColorLevel::None;
+        }
+
+        if var_os("NO_COLOR").is_some_and(|s| !s.is_empty()) {
+            return ColorLevel::None;
+        }
 
-        _ => unreachable!(),
+        term_color_level()
     }
 }
 
@@ -32,64 +308,150 @@ pub fn is_style_enabled() -> bool {
 /// Defaults to `Auto`.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum StyleMode {
-    /// Auto-detect if styling is supported. See [`StyleMode::auto`] for details.
+    /// Auto-detect the supported color level. See [`StyleMode::auto`] for details.
     #[default]
     Auto,
 
-    /// Force styling on/off.
-    Force(bool),
+    /// Force a specific color level.
+    Force(ColorLevel),
 }
 
 impl StyleMode {
-    /// Auto-detect if styling is supported.
+    /// Auto-detect the supported color level.
     ///
-    /// Specifically, if the `TERM` environment variable is set to `dumb`
-    /// or `NO_COLOR` is set, then styling is disabled. Otherwise it is enabled.
+    /// The following rules are checked in order, and the first one that applies
+    /// decides the color level:
     ///
-    /// In non-std environments, this always enables styling.
+    /// 1. If `FORCE_COLOR` is set and not empty, then it is `0` or `false` to
+    ///    disable styling, `2` for 256 colors, `3` for RGB colors, and anything
+    ///    else to enable styling with at least 16 colors.
+    /// 2. If `CLICOLOR_FORCE` is set, not empty and not `0`, then styling is
+    ///    enabled with at least 16 colors.
+    /// 3. If `CLICOLOR` is `0`, then styling is disabled.
+    /// 4. If the stream is not a terminal, then styling is disabled.
+    /// 5. If `TERM` is unset or set to `dumb`, then styling is disabled.
+    /// 6. If `NO_COLOR` is set and not empty, then styling is disabled.
+    /// 7. Otherwise, the color level is detected from `COLORTERM` (`truecolor` or
+    ///    `24bit`), `TERM` (for example, `xterm-256color` or `xterm-direct`) and
+    ///    terminal-specific variables such as `TERM_PROGRAM`.
+    ///
+    /// When styling is forced, the color level is still detected from the
+    /// terminal variables where possible.
+    ///
+    /// In non-std environments, this always enables all styling.
     #[inline]
     pub const fn auto() -> Self {
         Self::Auto
     }
 
-    /// Force-enable styling.
+    /// Force-enable styling, including RGB colors.
     #[inline]
     pub const fn enable() -> Self {
-        Self::Force(true)
+        Self::Force(ColorLevel::Rgb)
     }
 
     /// Force-disable styling.
     #[inline]
     pub const fn disable() -> Self {
-        Self::Force(false)
+        Self::Force(ColorLevel::None)
     }
 
-    fn should_enable_styling(&self) -> bool {
+    #[inline]
+    const fn into_bits(self) -> u8 {
         match self {
-            StyleMode::Auto => env_supports_styling(),
-            StyleMode::Force(b) => *b,
+            StyleMode::Auto => 0,
+            StyleMode::Force(level) => level as u8 + 1,
+        }
+    }
+
+    #[inline]
+    const fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => StyleMode::Auto,
+            n => StyleMode::Force(ColorLevel::from_bits(n - 1)),
         }
     }
 }
 
 #[cfg(feature = "std")]
-fn env_supports_styling() -> bool {
-    match std::env::var_os("TERM") {
-        Some(s) if s == "dumb" => return false,
-        None => return false,
+fn env_color_level(stream: Stream) -> ColorLevel {
+    use std::io::IsTerminal;
+
+    let is_terminal = match stream {
+        Stream::Stdout => std::io::stdout().is_terminal(),
+        Stream::Stderr => std::io::stderr().is_terminal(),
+        Stream::Custom => false,
+    };
+
+    ColorLevel::detect(is_terminal)
+}
+
+/// The color level forced by `FORCE_COLOR` or `CLICOLOR_FORCE`, if any.
+#[cfg(feature = "std")]
+fn forced_color_level() -> Option<ColorLevel> {
+    use std::env::var_os;
+
+    // An empty `FORCE_COLOR` is the same as it being unset.
+    if let Some(force) = var_os("FORCE_COLOR").filter(|s| !s.is_empty()) {
+        return Some(match force.to_str() {
+            Some("0" | "false") => ColorLevel::None,
+            Some("2") => ColorLevel::Ansi256,
+            Some("3") => ColorLevel::Rgb,
+            _ => term_color_level().max(ColorLevel::Ansi),
+        });
+    }
+
+    if var_os("CLICOLOR_FORCE").is_some_and(|s| !s.is_empty() && s != "0") {
+        return Some(term_color_level().max(ColorLevel::Ansi));
+    }
+
+    None
+}
+
+/// The color level supported by the terminal, based on `TERM` and other
+/// terminal-specific variables.
+#[cfg(feature = "std")]
+fn term_color_level() -> ColorLevel {
+    use std::env::var_os;
+
+    let term = match var_os("TERM") {
+        Some(term) if term != "dumb" => term,
+        _ => return ColorLevel::None,
+    };
+
+    if var_os("COLORTERM").is_some_and(|s| s == "truecolor" || s == "24bit") {
+        return ColorLevel::Rgb;
+    }
+
+    let term = term.to_string_lossy();
+
+    if term.ends_with("-direct") || term.contains("truecolor") || term.contains("24bit") {
+        return ColorLevel::Rgb;
+    }
+
+    match var_os("TERM_PROGRAM") {
+        Some(s) if s == "iTerm.app" || s == "WezTerm" || s == "vscode" => {
+            return ColorLevel::Rgb;
+        }
+        Some(s) if s == "Apple_Terminal" => return ColorLevel::Ansi256,
         _ => {}
     }
 
-    if std::env::var_os("NO_COLOR").is_some_and(|s| s != "0") {
-        return false;
+    // Windows Terminal
+    if var_os("WT_SESSION").is_some() {
+        return ColorLevel::Rgb;
+    }
+
+    if term.ends_with("256color") || term.ends_with("-256") {
+        return ColorLevel::Ansi256;
     }
 
-    true
+    ColorLevel::Ansi
 }
 
 #[cfg(not(feature = "std"))]
-fn env_supports_styling() -> bool {
+fn env_color_level(_stream: Stream) -> ColorLevel {
     // If there is no `std` we can presume we should use styling since
     // there is no sign that we should not.
-    true
+    ColorLevel::Rgb
 }
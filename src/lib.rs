@@ -2,16 +2,58 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(hidden)]
 pub mod macros;
 
+mod ansi;
 mod color;
+mod quantize;
+mod sgr;
+mod spec;
+mod strip;
 mod style;
+mod stylize;
+mod truncate;
 
 mod display;
 mod enable;
+mod options;
+mod visible;
+mod width;
+
+#[cfg(feature = "alloc")]
+mod text;
 
+#[cfg(feature = "nested_styles")]
+mod nesting;
+
+pub use ansi::{parse_ansi, AnsiEvent, AnsiParser};
 pub use color::{AnsiColor, Color};
-pub use style::{Attributes, Hyperlink, Style, Styled};
+pub use sgr::{Sgr, StaticStyle};
+pub use spec::{ParseColorError, ParseStyleError, ParseStyleErrorKind, StyleSpec};
+pub use style::{
+    AsDebug, Attributes, ForStream, Hyperlink, Inherit, StaticStyled, Style, StyleTransition,
+    Styled, UnderlineStyle,
+};
+
+pub use stylize::Stylize;
+pub use truncate::Truncated;
+
+pub use strip::StripWriter;
+pub use width::WidthCounter;
+
+#[cfg(feature = "alloc")]
+pub use strip::strip_ansi;
+#[cfg(feature = "alloc")]
+pub use text::StyledText;
+
+pub use enable::{
+    current_color_level, get_style_mode, is_style_enabled, set_style_mode, ColorLevel, Stream,
+    StyleMode,
+};
 
-pub use enable::{is_style_enabled, set_style_mode, StyleMode};
+#[cfg(feature = "std")]
+pub use enable::with_style_mode;
@@ -1,7 +1,13 @@
 use core::fmt;
 
+use crate::options::{Content, Options};
+
 /// Create a styled value (see [`Styled`](crate::Styled)).
 ///
+/// Width and precision apply to the formatted content as they would to a string, with
+/// padding measured by visible width. Like for a string, flags such as `+` have no
+/// effect, so put them in the format string instead, as in `styled!("{:+}", n)`.
+///
 /// # Examples
 ///
 /// ```rust
@@ -110,6 +116,12 @@ impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> FormatArgsCallback<F> {
 impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for FormatArgsCallback<F> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        (self.0)(f)
+        if f.width().is_none() && f.precision().is_none() {
+            return (self.0)(f);
+        }
+
+        // The callback writes its output directly, so the formatter's width and
+        // precision are applied to it like they would be for a string.
+        Options::of(f).write_unformatted(f, &Content(&self.0))
     }
 }
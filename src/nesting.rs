@@ -0,0 +1,191 @@
+//! Restores the style of a styled value whenever its content resets the style, such as
+//! at the end of a nested styled value.
+//!
+//! The content is written through a [`Nested`] writer that knows the value's style, so
+//! the enclosing style is never stored anywhere else, and nesting works the same with or
+//! without `std`.
+
+use core::fmt::{self, Write};
+
+use crate::sgr::transition_sgr;
+use crate::{ColorLevel, Style};
+
+/// The longest SGR sequence in the content whose style can be followed. Longer ones are
+/// passed on as they are, after which resets restore the whole style.
+const PARAMS_CAPACITY: usize = 64;
+
+/// Writes the content of a styled value, replacing every reset in it (`ESC [ m` or
+/// `ESC [ 0 m`) with the value's own style.
+///
+/// The writer follows the style that the content changes to, such as the style of a
+/// nested value, so a reset only changes what differs from the value's style.
+pub(crate) struct Nested<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    style: Style,
+    sgr: &'a str,
+    inherit: bool,
+    level: ColorLevel,
+    current: Option<Style>,
+    state: State,
+    params: [u8; PARAMS_CAPACITY],
+}
+
+/// How much of an SGR sequence has been written so far. Nothing is passed on until it
+/// is known whether the sequence is a reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Text,
+    Escape,
+    Csi { len: usize },
+}
+
+impl<'a, 'b> Nested<'a, 'b> {
+    /// Wrap a formatter for a value with `style`, that was opened with `sgr`.
+    ///
+    /// If the value inherits, `sgr` only sets what the value's style changes, so the
+    /// style of the content isn't fully known. A full reset is then written before
+    /// `sgr`, which the enclosing value restores its own style for.
+    #[inline]
+    pub(crate) fn new(
+        inner: &'a mut fmt::Formatter<'b>,
+        style: Style,
+        sgr: &'a str,
+        inherit: bool,
+        level: ColorLevel,
+    ) -> Self {
+        Self {
+            inner,
+            style,
+            sgr,
+            inherit,
+            level,
+            current: (!inherit).then_some(style),
+            state: State::Text,
+            params: [0; PARAMS_CAPACITY],
+        }
+    }
+
+    /// Write anything that was held back at the end of the content.
+    #[inline]
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        self.write_pending()
+    }
+
+    fn write_sgr(&mut self, len: usize) -> fmt::Result {
+        // Only ASCII digits and separators are held back.
+        let params = core::str::from_utf8(&self.params[..len]).unwrap_or_default();
+
+        if params.bytes().all(|b| b == b'0') {
+            return self.restore();
+        }
+
+        self.inner.write_str("\x1b[")?;
+        self.inner.write_str(params)?;
+        self.inner.write_char('m')?;
+
+        if let Some(current) = &mut self.current {
+            if !crate::ansi::apply_sgr(current, params) {
+                self.current = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn restore(&mut self) -> fmt::Result {
+        let current = self.current;
+        self.current = (!self.inherit).then_some(self.style);
+
+        match current {
+            Some(current) => {
+                let sgr = transition_sgr(current, self.style, self.level);
+                self.inner.write_str(sgr.as_str())
+            }
+            None => {
+                if self.inherit {
+                    self.inner.write_str("\x1b[0m")?;
+                }
+
+                self.inner.write_str(self.sgr)
+            }
+        }
+    }
+
+    fn write_pending(&mut self) -> fmt::Result {
+        match core::mem::replace(&mut self.state, State::Text) {
+            State::Text => Ok(()),
+            State::Escape => self.inner.write_str("\x1b"),
+            State::Csi { len } => {
+                self.inner.write_str("\x1b[")?;
+                self.params[..len]
+                    .iter()
+                    .try_for_each(|&b| self.inner.write_char(b as char))
+            }
+        }
+    }
+}
+
+impl fmt::Write for Nested<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+
+        // The start of the text that hasn't been written or held back yet.
+        let mut start = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if self.state == State::Text {
+                match bytes[i..].iter().position(|&b| b == b'\x1b') {
+                    Some(offset) => i += offset,
+                    None => break,
+                }
+
+                self.inner.write_str(&s[start..i])?;
+                self.state = State::Escape;
+                i += 1;
+                start = i;
+                continue;
+            }
+
+            self.state = match (self.state, bytes[i]) {
+                (State::Escape, b'[') => State::Csi { len: 0 },
+                (State::Csi { len }, b'0'..=b'9' | b';' | b':') if len < PARAMS_CAPACITY => {
+                    self.params[len] = bytes[i];
+                    State::Csi { len: len + 1 }
+                }
+                (State::Csi { len }, b'm') => {
+                    self.state = State::Text;
+                    self.write_sgr(len)?;
+                    i += 1;
+                    start = i;
+                    continue;
+                }
+
+                // Too long to follow, so the rest of the sequence is written as it is.
+                (State::Csi { .. }, b'0'..=b'9' | b';' | b':') => {
+                    self.current = None;
+                    self.write_pending()?;
+                    start = i;
+                    continue;
+                }
+
+                // Not an SGR sequence, so write what was held back and handle this byte
+                // as text.
+                _ => {
+                    self.write_pending()?;
+                    start = i;
+                    continue;
+                }
+            };
+
+            i += 1;
+            start = i;
+        }
+
+        if self.state == State::Text {
+            self.inner.write_str(&s[start..])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Formatting options for the content of styled values.
+
+use core::fmt::{self, Write};
+
+use crate::visible::Truncate;
+use crate::WidthCounter;
+
+/// Displays the content of a styled value using the given callback.
+pub(crate) struct Content<F>(pub(crate) F);
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for Content<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// The options of a formatter, which are applied again when the content of a styled
+/// value is written through another writer, or padded by visible width.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Options {
+    fill: char,
+    align: Option<fmt::Alignment>,
+    width: Option<usize>,
+    precision: Option<usize>,
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+}
+
+impl Options {
+    #[inline]
+    pub(crate) fn of(f: &fmt::Formatter) -> Self {
+        Self {
+            fill: f.fill(),
+            align: f.align(),
+            width: f.width(),
+            precision: f.precision(),
+            plus: f.sign_plus(),
+            alternate: f.alternate(),
+            zero: f.sign_aware_zero_pad(),
+        }
+    }
+
+    /// Write a value with these options.
+    #[inline]
+    pub(crate) fn write(self, w: &mut dyn fmt::Write, value: &dyn fmt::Display) -> fmt::Result {
+        if self.width.is_none() && self.precision.is_none() && !self.plus && !self.alternate {
+            return write!(w, "{value}");
+        }
+
+        self.write_padded(w, value)
+    }
+
+    /// Write a value that ignores the formatter's options, such as the output of a
+    /// callback, truncating it to the precision and padding it to the width like a
+    /// string.
+    #[cold]
+    pub(crate) fn write_unformatted(
+        self,
+        w: &mut dyn fmt::Write,
+        value: &dyn fmt::Display,
+    ) -> fmt::Result {
+        let truncated = |w: &mut dyn fmt::Write| match self.precision {
+            Some(precision) => write!(Truncate::new(w, precision), "{value}"),
+            None => write!(w, "{value}"),
+        };
+
+        match self.width {
+            Some(width) => self.pad(
+                w,
+                width,
+                self.align.unwrap_or(fmt::Alignment::Left),
+                truncated,
+            ),
+            None => truncated(w),
+        }
+    }
+
+    // A formatter's options can't be passed on to another formatter directly. Padding is
+    // written here, measured by visible width, so that any fill character can be used.
+    // Without an explicit alignment, the value is aligned the way it aligns itself.
+    #[cold]
+    fn write_padded(self, w: &mut dyn fmt::Write, value: &dyn fmt::Display) -> fmt::Result {
+        let width = match self.width {
+            Some(width) if !self.zero => width,
+            _ => return self.write_flags(w, value),
+        };
+
+        let unpadded = Self {
+            width: None,
+            ..self
+        };
+
+        let align = match self.align {
+            Some(align) => align,
+            None => match unpadded.default_align(value)? {
+                Some(align) => align,
+                None => return unpadded.write_flags(w, value),
+            },
+        };
+
+        self.pad(w, width, align, |w| unpadded.write_flags(w, value))
+    }
+
+    fn pad(
+        self,
+        w: &mut dyn fmt::Write,
+        width: usize,
+        align: fmt::Alignment,
+        value: impl Fn(&mut dyn fmt::Write) -> fmt::Result,
+    ) -> fmt::Result {
+        let mut counter = WidthCounter::new();
+        value(&mut counter)?;
+
+        let padding = width.saturating_sub(counter.width());
+        let (pre, post) = match align {
+            fmt::Alignment::Left => (0, padding),
+            fmt::Alignment::Right => (padding, 0),
+            fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+        };
+
+        (0..pre).try_for_each(|_| w.write_char(self.fill))?;
+        value(w)?;
+        (0..post).try_for_each(|_| w.write_char(self.fill))
+    }
+
+    // Numbers are aligned right by default and most other types left, so the value is
+    // padded to see which side it ends up on. The width is more than the value could be
+    // measured as, whether in characters or in columns. `None` means that the value
+    // ignores the width. Text that starts and ends with spaces can't be told apart, and
+    // is aligned left.
+    fn default_align(self, value: &dyn fmt::Display) -> Result<Option<fmt::Alignment>, fmt::Error> {
+        let mut unpadded = Probe::default();
+        self.write_flags(&mut unpadded, value)?;
+
+        let mut padded = Probe::default();
+        Self {
+            width: Some(unpadded.chars * 2 + 1),
+            ..self
+        }
+        .write_flags(&mut padded, value)?;
+
+        Ok(if padded.chars == unpadded.chars {
+            None
+        } else if padded.last != unpadded.last {
+            Some(fmt::Alignment::Left)
+        } else if padded.first != unpadded.first {
+            Some(fmt::Alignment::Right)
+        } else {
+            Some(fmt::Alignment::Left)
+        })
+    }
+
+    fn write_flags(self, w: &mut dyn fmt::Write, value: &dyn fmt::Display) -> fmt::Result {
+        macro_rules! write_flags {
+            (@flag $flag:ident $spec:literal, $($rest:tt)*) => {
+                if self.$flag {
+                    write_flags!($($rest)* $spec)
+                } else {
+                    write_flags!($($rest)*)
+                }
+            };
+
+            ($($spec:literal)*) => {
+                match (self.width, self.precision) {
+                    (None, None) => write!(w, concat!("{:", $($spec,)* "}"), value),
+                    (Some(width), None) => {
+                        write!(w, concat!("{:", $($spec,)* "width$}"), value, width = width)
+                    }
+                    (None, Some(precision)) => write!(
+                        w,
+                        concat!("{:", $($spec,)* ".precision$}"),
+                        value,
+                        precision = precision,
+                    ),
+                    (Some(width), Some(precision)) => write!(
+                        w,
+                        concat!("{:", $($spec,)* "width$.precision$}"),
+                        value,
+                        width = width,
+                        precision = precision,
+                    ),
+                }
+            };
+        }
+
+        // The flags have to be in this order in a format string.
+        write_flags!(@flag plus "+", @flag alternate "#", @flag zero "0",)
+    }
+}
+
+/// Counts the characters written to it, and remembers the first and last one.
+#[derive(Default)]
+struct Probe {
+    chars: usize,
+    first: Option<char>,
+    last: Option<char>,
+}
+
+impl fmt::Write for Probe {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.first.get_or_insert(c);
+            self.last = Some(c);
+            self.chars += 1;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{AnsiColor, Color, ColorLevel};
+
+type Rgb = (u8, u8, u8);
+
+// The default xterm palette. Terminals are free to use their own palette for the
+// 4-bit colors, but this is a reasonable approximation for picking the nearest one.
+const ANSI_PALETTE: [(AnsiColor, Rgb); 16] = [
+    (AnsiColor::Black, (0, 0, 0)),
+    (AnsiColor::Red, (205, 0, 0)),
+    (AnsiColor::Green, (0, 205, 0)),
+    (AnsiColor::Yellow, (205, 205, 0)),
+    (AnsiColor::Blue, (0, 0, 238)),
+    (AnsiColor::Magenta, (205, 0, 205)),
+    (AnsiColor::Cyan, (0, 205, 205)),
+    (AnsiColor::White, (229, 229, 229)),
+    (AnsiColor::BrightBlack, (127, 127, 127)),
+    (AnsiColor::BrightRed, (255, 0, 0)),
+    (AnsiColor::BrightGreen, (0, 255, 0)),
+    (AnsiColor::BrightYellow, (255, 255, 0)),
+    (AnsiColor::BrightBlue, (92, 92, 255)),
+    (AnsiColor::BrightMagenta, (255, 0, 255)),
+    (AnsiColor::BrightCyan, (0, 255, 255)),
+    (AnsiColor::BrightWhite, (255, 255, 255)),
+];
+
+// Channel values of the 6x6x6 color cube in the 8-bit palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Convert the color to the nearest color that can be displayed at the given color level.
+    ///
+    /// RGB colors are mapped to the 8-bit palette, and 8-bit colors are mapped to the
+    /// 4-bit palette, by picking the perceptually nearest color. If the level doesn't
+    /// support colors, this returns [`Color::Default`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{AnsiColor, Color, ColorLevel};
+    ///
+    /// assert_eq!(Color::Rgb(255, 0, 0).quantize(ColorLevel::Ansi256), Color::Ansi256(196));
+    /// assert_eq!(Color::Rgb(255, 0, 0).quantize(ColorLevel::Ansi), Color::Ansi(AnsiColor::BrightRed));
+    /// assert_eq!(Color::Rgb(255, 0, 0).quantize(ColorLevel::Attributes), Color::Default);
+    /// ```
+    pub const fn quantize(self, level: ColorLevel) -> Color {
+        if !level.supports_color() {
+            return Color::Default;
+        }
+
+        match (self, level) {
+            (Color::Ansi256(i), ColorLevel::Ansi) => Color::Ansi(ansi256_to_ansi(i)),
+
+            (Color::Rgb(r, g, b), ColorLevel::Ansi) => Color::Ansi(rgb_to_ansi((r, g, b))),
+            (Color::Rgb(r, g, b), ColorLevel::Ansi256) => Color::Ansi256(rgb_to_ansi256((r, g, b))),
+
+            (color, _) => color,
+        }
+    }
+}
+
+const fn rgb_to_ansi(rgb: Rgb) -> AnsiColor {
+    let mut nearest = 0;
+    let mut nearest_distance = u32::MAX;
+
+    let mut i = 0;
+    while i < ANSI_PALETTE.len() {
+        let distance = distance(rgb, ANSI_PALETTE[i].1);
+        if distance < nearest_distance {
+            nearest = i;
+            nearest_distance = distance;
+        }
+        i += 1;
+    }
+
+    ANSI_PALETTE[nearest].0
+}
+
+const fn rgb_to_ansi256(rgb: Rgb) -> u8 {
+    const fn cube_index(v: u8) -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    }
+
+    let (ri, gi, bi) = (cube_index(rgb.0), cube_index(rgb.1), cube_index(rgb.2));
+    let cube = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray_index = match average {
+        0..=7 => 0,
+        239.. => 23,
+        _ => ((average - 3) / 10) as u8,
+    };
+    let gray_level = 8 + 10 * gray_index;
+
+    if distance(rgb, (gray_level, gray_level, gray_level)) < distance(rgb, cube) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+const fn ansi256_to_ansi(i: u8) -> AnsiColor {
+    match i {
+        0..=15 => ANSI_PALETTE[i as usize].0,
+        _ => rgb_to_ansi(ansi256_to_rgb(i)),
+    }
+}
+
+const fn ansi256_to_rgb(i: u8) -> Rgb {
+    match i {
+        0..=15 => ANSI_PALETTE[i as usize].1,
+
+        16..=231 => {
+            let i = i - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+
+        _ => {
+            let level = 8 + 10 * (i - 232);
+            (level, level, level)
+        }
+    }
+}
+
+// The "redmean" approximation of perceptual color difference, which weights each
+// channel based on how sensitive the eye is to it. See
+// <https://www.compuphase.com/cmetric.htm>.
+//
+// Returns the squared distance (scaled by 256), which is fine since we only compare distances.
+const fn distance(a: Rgb, b: Rgb) -> u32 {
+    let rmean = (a.0 as i32 + b.0 as i32) / 2;
+
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+
+    (((512 + rmean) * dr * dr) + 1024 * dg * dg + ((767 - rmean) * db * db)) as u32
+}
@@ -0,0 +1,564 @@
+use core::{fmt, ops::Deref, str};
+
+use crate::style::{StaticStyled, UnderlineStyle};
+use crate::{Attributes, Color, ColorLevel, Style};
+
+/// The escape sequence that applies a [`Style`], generated by [`Style::sgr`].
+///
+/// The sequence is stored inline on the stack, so it can be generated without
+/// allocating, and in `const` contexts. Use [`sgr`](crate::sgr!) to generate a `&'static str` at
+/// compile time.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sgr {
+    bytes: [u8; Sgr::CAPACITY],
+    len: u8,
+}
+
+impl Sgr {
+    // The longest style is 93 bytes, with every attribute, a styled underline, and
+    // three RGB colors. The longest transition is 97 bytes (see `LONGEST_TRANSITION`).
+    const CAPACITY: usize = 97;
+
+    const EMPTY: Self = Self {
+        bytes: [0; Self::CAPACITY],
+        len: 0,
+    };
+
+    /// The escape sequence as a string.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.bytes.split_at(self.len as usize);
+        match str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    const fn push(&mut self, s: &str) {
+        let s = s.as_bytes();
+
+        let mut i = 0;
+        while i < s.len() {
+            self.bytes[self.len as usize] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+}
+
+// In a transition, each group of attributes that shares a reset parameter is either
+// turned off, set, or turned off and then partly set again (as from bold to dim). These
+// are the longest parameters for each group, followed by three RGB colors.
+const LONGEST_TRANSITION: usize = "\x1b".len()
+    + ";22;2".len()
+    + ";23".len()
+    + ";24;4:3".len()
+    + ";25;6".len()
+    + ";27".len()
+    + ";28".len()
+    + ";29".len()
+    + ";54;52".len()
+    + ";55".len()
+    + ";75;74".len()
+    + 3 * ";38;2;255;255;255".len()
+    + "m".len();
+
+const _: () = assert!(LONGEST_TRANSITION <= Sgr::CAPACITY);
+
+impl Deref for Sgr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Sgr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Sgr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Sgr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Style {
+    /// Generate the escape sequence that applies this style, assuming RGB colors are
+    /// supported.
+    ///
+    /// This is the same sequence that is written when the style is displayed, but it
+    /// doesn't depend on the [style mode](crate::set_style_mode), so it can be computed
+    /// at compile time. See also the [`sgr`](crate::sgr!) macro.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{Sgr, Style};
+    ///
+    /// const SGR: Sgr = Style::new().bold().red().sgr();
+    /// assert_eq!(SGR.as_str(), "\x1b[0;31;1m");
+    /// ```
+    #[inline]
+    pub const fn sgr(self) -> Sgr {
+        self.sgr_at(ColorLevel::Rgb)
+    }
+
+    /// Generate the escape sequence that applies this style at the given color level.
+    ///
+    /// Colors are [quantized](Color::quantize) to the level. If the level is
+    /// [`ColorLevel::None`], the sequence is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{ColorLevel, Style};
+    ///
+    /// let style = Style::new().rgb_color(255, 0, 0);
+    /// assert_eq!(style.sgr_at(ColorLevel::Ansi256).as_str(), "\x1b[0;38;5;196m");
+    /// assert_eq!(style.sgr_at(ColorLevel::None).as_str(), "");
+    /// ```
+    pub const fn sgr_at(self, level: ColorLevel) -> Sgr {
+        let mut sgr = Sgr::EMPTY;
+
+        if matches!(level, ColorLevel::None) {
+            return sgr;
+        }
+
+        let style = quantize_style(self, level);
+
+        sgr.push("\x1b[0");
+
+        push_color(&mut sgr, style.fg, "3", "9");
+        push_color(&mut sgr, style.bg, "4", "10");
+
+        // A styled underline is written separately, after the other attributes.
+        // `quantize_style` resets the style if the text isn't underlined.
+        match style.underline_style {
+            UnderlineStyle::Single => push_attributes(&mut sgr, style.attributes),
+            underline_style => {
+                push_attributes(&mut sgr, style.attributes.and(Attributes::UNDERLINED.not()));
+                sgr.push(underline_param(underline_style));
+            }
+        }
+
+        push_underline_color(&mut sgr, style.underline_color);
+
+        sgr.push("m");
+
+        sgr
+    }
+}
+
+/// Generate the escape sequence that applies a constant [`Style`] as a `&'static str`
+/// at compile time.
+///
+/// By default, the sequence assumes RGB colors are supported. A [`ColorLevel`] can be
+/// given as a second argument. Unlike displaying a style, this doesn't depend on the
+/// [style mode](crate::set_style_mode).
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{sgr, ColorLevel, Style};
+///
+/// const MY_STYLE: Style = Style::new().bold().blue();
+///
+/// const SGR: &str = sgr!(MY_STYLE);
+/// assert_eq!(SGR, "\x1b[0;34;1m");
+///
+/// assert_eq!(sgr!(MY_STYLE, ColorLevel::Attributes), "\x1b[0;1m");
+/// ```
+#[macro_export]
+macro_rules! sgr {
+    ($style:expr $(,)?) => {
+        $crate::sgr!($style, $crate::ColorLevel::Rgb)
+    };
+    ($style:expr, $level:expr $(,)?) => {{
+        const SGR: &$crate::Sgr = &$crate::Style::sgr_at($style, $level);
+        SGR.as_str()
+    }};
+}
+
+/// A [`Style`] along with its escape sequence at every [`ColorLevel`], generated at
+/// compile time.
+///
+/// Displaying a value styled with it writes the precomputed sequence for the current
+/// color level in one go, rather than generating it each time. Declare it as a
+/// `static` and style values with [`StaticStyle::apply`].
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{set_style_mode, StaticStyle, Style, StyleMode};
+///
+/// static ERROR: StaticStyle = StaticStyle::new(Style::new().bold().red());
+///
+/// set_style_mode(StyleMode::enable());
+/// assert_eq!(ERROR.apply("failed").to_string(), "\x1b[0;31;1mfailed\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticStyle {
+    style: Style,
+    sgrs: [Sgr; 5],
+}
+
+impl StaticStyle {
+    /// Generate the escape sequences for a style.
+    pub const fn new(style: Style) -> Self {
+        Self {
+            style,
+            sgrs: [
+                style.sgr_at(ColorLevel::None),
+                style.sgr_at(ColorLevel::Attributes),
+                style.sgr_at(ColorLevel::Ansi),
+                style.sgr_at(ColorLevel::Ansi256),
+                style.sgr_at(ColorLevel::Rgb),
+            ],
+        }
+    }
+
+    /// The style.
+    #[inline]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The escape sequence that applies the style at the given color level. This is
+    /// the same as [`Style::sgr_at`].
+    #[inline]
+    pub const fn sgr_at(&self, level: ColorLevel) -> &Sgr {
+        &self.sgrs[level as usize]
+    }
+
+    /// Style a value.
+    #[inline]
+    pub const fn apply<T>(&'static self, content: T) -> StaticStyled<T> {
+        StaticStyled {
+            content,
+            style: self,
+        }
+    }
+}
+
+/// Generate an escape sequence that changes from one style to another at the given
+/// color level, only writing the parameters that differ.
+pub(crate) fn transition_sgr(from: Style, to: Style, level: ColorLevel) -> Sgr {
+    let mut sgr = Sgr::EMPTY;
+
+    if matches!(level, ColorLevel::None) {
+        return sgr;
+    }
+
+    let from = quantize_style(from, level);
+    let to = quantize_style(to, level);
+
+    if from == to {
+        return sgr;
+    }
+
+    if to.is_default() {
+        sgr.push("\x1b[0m");
+        return sgr;
+    }
+
+    // Every parameter is written with a leading separator. The first one is replaced
+    // with the `[` that starts the sequence at the end.
+    sgr.push("\x1b");
+
+    let mut attributes = from.attributes;
+    for (reset, reset_param) in ATTRIBUTE_RESETS {
+        if !from
+            .attributes
+            .and(reset)
+            .and(to.attributes.not())
+            .is_empty()
+        {
+            sgr.push(reset_param);
+            attributes = attributes.and(reset.not());
+        }
+    }
+
+    let mut added = to.attributes.and(attributes.not());
+
+    // Write the underline again if only its style changed.
+    if to.attributes.contains(Attributes::UNDERLINED) && from.underline_style != to.underline_style
+    {
+        added = added.or(Attributes::UNDERLINED);
+    }
+
+    match to.underline_style {
+        UnderlineStyle::Single => push_attributes(&mut sgr, added),
+        _ if !added.contains(Attributes::UNDERLINED) => push_attributes(&mut sgr, added),
+        underline_style => {
+            push_attributes(&mut sgr, added.and(Attributes::UNDERLINED.not()));
+            sgr.push(underline_param(underline_style));
+        }
+    }
+
+    if from.fg != to.fg {
+        match to.fg {
+            Color::Default => sgr.push(";39"),
+            color => push_color(&mut sgr, color, "3", "9"),
+        }
+    }
+
+    if from.bg != to.bg {
+        match to.bg {
+            Color::Default => sgr.push(";49"),
+            color => push_color(&mut sgr, color, "4", "10"),
+        }
+    }
+
+    if from.underline_color != to.underline_color {
+        match to.underline_color {
+            Color::Default => sgr.push(";59"),
+            color => push_underline_color(&mut sgr, color),
+        }
+    }
+
+    // `from` and `to` differ, so at least one parameter was written.
+    sgr.bytes[1] = b'[';
+    sgr.push("m");
+
+    sgr
+}
+
+// SGR parameters that turn attributes off. Some parameters turn off more than one attribute.
+const ATTRIBUTE_RESETS: [(Attributes, &str); 10] = [
+    (Attributes::BOLD.or(Attributes::DIM), ";22"),
+    (Attributes::ITALIC, ";23"),
+    (
+        Attributes::UNDERLINED.or(Attributes::DOUBLE_UNDERLINED),
+        ";24",
+    ),
+    (Attributes::BLINKING.or(Attributes::RAPID_BLINKING), ";25"),
+    (Attributes::INVERTED, ";27"),
+    (Attributes::HIDDEN, ";28"),
+    (Attributes::CROSSED, ";29"),
+    (Attributes::FRAMED.or(Attributes::ENCIRCLED), ";54"),
+    (Attributes::OVERLINED, ";55"),
+    (Attributes::SUPERSCRIPT.or(Attributes::SUBSCRIPT), ";75"),
+];
+
+/// Convert a style to what will actually be written at the given color level.
+#[inline]
+const fn quantize_style(style: Style, level: ColorLevel) -> Style {
+    // Underline styles and colors are newer than RGB colors, so only use them if
+    // RGB colors are supported.
+    let extended_underline = matches!(level, ColorLevel::Rgb);
+
+    Style {
+        fg: style.fg.quantize(level),
+        bg: style.bg.quantize(level),
+        attributes: style.attributes,
+        underline_style: if extended_underline && style.attributes.contains(Attributes::UNDERLINED)
+        {
+            style.underline_style
+        } else {
+            UnderlineStyle::Single
+        },
+        underline_color: if extended_underline {
+            style.underline_color
+        } else {
+            Color::Default
+        },
+    }
+}
+
+#[inline]
+const fn underline_param(underline_style: UnderlineStyle) -> &'static str {
+    match underline_style {
+        UnderlineStyle::Single => ";4",
+        UnderlineStyle::Curly => ";4:3",
+        UnderlineStyle::Dotted => ";4:4",
+        UnderlineStyle::Dashed => ";4:5",
+    }
+}
+
+const fn push_color(sgr: &mut Sgr, color: Color, prefix: &str, bright_prefix: &str) {
+    match color {
+        Color::Default => {}
+
+        Color::Ansi(color) => {
+            let i = color as u8;
+            sgr.push(";");
+            sgr.push(if i < 8 { prefix } else { bright_prefix });
+            sgr.push(U8_STRINGS[(i % 8) as usize]);
+        }
+
+        Color::Ansi256(i) => {
+            sgr.push(";");
+            sgr.push(prefix);
+            sgr.push("8;5;");
+            sgr.push(U8_STRINGS[i as usize]);
+        }
+
+        Color::Rgb(r, g, b) => {
+            sgr.push(";");
+            sgr.push(prefix);
+            sgr.push("8;2;");
+            push_rgb(sgr, r, g, b);
+        }
+    }
+}
+
+const fn push_underline_color(sgr: &mut Sgr, color: Color) {
+    match color {
+        Color::Default => {}
+
+        // There are no 4-bit underline colors, but the first 16 colors of the 8-bit
+        // palette are the same.
+        Color::Ansi(color) => {
+            sgr.push(";58;5;");
+            sgr.push(U8_STRINGS[color as usize]);
+        }
+
+        Color::Ansi256(i) => {
+            sgr.push(";58;5;");
+            sgr.push(U8_STRINGS[i as usize]);
+        }
+
+        Color::Rgb(r, g, b) => {
+            sgr.push(";58;2;");
+            push_rgb(sgr, r, g, b);
+        }
+    }
+}
+
+const fn push_rgb(sgr: &mut Sgr, r: u8, g: u8, b: u8) {
+    sgr.push(U8_STRINGS[r as usize]);
+    sgr.push(";");
+    sgr.push(U8_STRINGS[g as usize]);
+    sgr.push(";");
+    sgr.push(U8_STRINGS[b as usize]);
+}
+
+const fn push_attributes(sgr: &mut Sgr, attributes: Attributes) {
+    let [high, low] = attributes.into_bits().to_be_bytes();
+
+    sgr.push(HIGH_ATTRIBUTE_LOOKUP[high as usize]);
+    sgr.push(LOW_ATTRIBUTE_LOOKUP[low as usize]);
+}
+
+// The decimal representation of every `u8`, so that numbers can be written
+// without going through the formatting machinery.
+const U8_STRINGS: [&str; 256] = {
+    const DIGITS: &[[u8; 3]; 256] = &{
+        let mut digits = [[0; 3]; 256];
+        let mut i = 0;
+        while i < 256 {
+            digits[i] = [
+                b'0' + (i / 100) as u8,
+                b'0' + (i / 10 % 10) as u8,
+                b'0' + (i % 10) as u8,
+            ];
+            i += 1;
+        }
+        digits
+    };
+
+    let mut strings = [""; 256];
+    let mut i = 0;
+    while i < 256 {
+        let len = match i {
+            0..=9 => 1,
+            10..=99 => 2,
+            _ => 3,
+        };
+
+        let (_, digits) = DIGITS[i].split_at(3 - len);
+        strings[i] = match str::from_utf8(digits) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        };
+
+        i += 1;
+    }
+
+    strings
+};
+
+// The attributes are split into two bytes, and each byte is looked up in a table of
+// all the parameter strings for that byte, which are generated at compile time. Like the
+// rest of the parameters, these are written from the highest bit to the lowest.
+const LOW_ATTRIBUTE_LOOKUP: [&str; 256] = {
+    const BUFFERS: &[AttributeBuffer; 256] =
+        &attribute_buffers(&["1", "2", "3", "4", "5", "7", "8", "9"]);
+    attribute_strings(BUFFERS)
+};
+
+const HIGH_ATTRIBUTE_LOOKUP: [&str; 256] = {
+    const BUFFERS: &[AttributeBuffer; 256] =
+        &attribute_buffers(&["6", "21", "51", "52", "53", "73", "74"]);
+    attribute_strings(BUFFERS)
+};
+
+#[derive(Clone, Copy)]
+struct AttributeBuffer {
+    bytes: [u8; 24],
+    len: usize,
+}
+
+const fn attribute_buffers(params: &[&str]) -> [AttributeBuffer; 256] {
+    let mut buffers = [AttributeBuffer {
+        bytes: [0; 24],
+        len: 0,
+    }; 256];
+
+    let mut bits = 0;
+    while bits < 256 {
+        let buffer = &mut buffers[bits];
+
+        let mut i = params.len();
+        while i > 0 {
+            i -= 1;
+
+            if bits & (1 << i) == 0 {
+                continue;
+            }
+
+            buffer.bytes[buffer.len] = b';';
+            buffer.len += 1;
+
+            let param = params[i].as_bytes();
+            let mut j = 0;
+            while j < param.len() {
+                buffer.bytes[buffer.len] = param[j];
+                buffer.len += 1;
+                j += 1;
+            }
+        }
+
+        bits += 1;
+    }
+
+    buffers
+}
+
+const fn attribute_strings(buffers: &'static [AttributeBuffer; 256]) -> [&'static str; 256] {
+    let mut strings = [""; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let (bytes, _) = buffers[i].bytes.split_at(buffers[i].len);
+        strings[i] = match str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        };
+        i += 1;
+    }
+
+    strings
+}
@@ -0,0 +1,583 @@
+use core::{fmt, str::FromStr};
+
+use crate::{AnsiColor, Attributes, Color, Style, UnderlineStyle};
+
+const ANSI_NAMES: [(AnsiColor, &str); 16] = [
+    (AnsiColor::Black, "black"),
+    (AnsiColor::Red, "red"),
+    (AnsiColor::Green, "green"),
+    (AnsiColor::Yellow, "yellow"),
+    (AnsiColor::Blue, "blue"),
+    (AnsiColor::Magenta, "magenta"),
+    (AnsiColor::Cyan, "cyan"),
+    (AnsiColor::White, "white"),
+    (AnsiColor::BrightBlack, "bright_black"),
+    (AnsiColor::BrightRed, "bright_red"),
+    (AnsiColor::BrightGreen, "bright_green"),
+    (AnsiColor::BrightYellow, "bright_yellow"),
+    (AnsiColor::BrightBlue, "bright_blue"),
+    (AnsiColor::BrightMagenta, "bright_magenta"),
+    (AnsiColor::BrightCyan, "bright_cyan"),
+    (AnsiColor::BrightWhite, "bright_white"),
+];
+
+/// An error returned when parsing a [`Color`] or [`AnsiColor`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseColorError {
+    /// The input was empty.
+    Empty,
+
+    /// A hex color wasn't of the form `#rgb` or `#rrggbb`.
+    InvalidHex,
+
+    /// An RGB color wasn't of the form `rgb(r, g, b)`, with each component from 0 to 255.
+    InvalidRgb,
+
+    /// A palette index was greater than 255.
+    InvalidIndex,
+
+    /// The input wasn't a known color name.
+    UnknownName,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty color",
+            Self::InvalidHex => "invalid hex color, expected `#rgb` or `#rrggbb`",
+            Self::InvalidRgb => {
+                "invalid RGB color, expected `rgb(r, g, b)` with components from 0 to 255"
+            }
+            Self::InvalidIndex => "invalid palette index, expected a number from 0 to 255",
+            Self::UnknownName => "unknown color name",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {}
+
+/// Parses an ANSI color name, such as `red` or `bright_red`.
+///
+/// Names are case-insensitive, and `-` may be used instead of `_`.
+impl FromStr for AnsiColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+
+        ANSI_NAMES
+            .iter()
+            .find(|(_, name)| name_eq(s, name))
+            .map(|(color, _)| *color)
+            .ok_or(ParseColorError::UnknownName)
+    }
+}
+
+/// Formats the color as its name, such as `bright_red`.
+impl fmt::Display for AnsiColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(ANSI_NAMES[*self as usize].1)
+    }
+}
+
+/// Parses a color.
+///
+/// The following formats are supported:
+/// - `default`
+/// - ANSI color names, such as `red` or `bright_red` (see [`AnsiColor`])
+/// - 8-bit palette indices from `0` to `255`
+/// - hex colors, such as `#f80` or `#ff8800`
+/// - RGB colors, such as `rgb(255, 136, 0)`
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{AnsiColor, Color};
+///
+/// assert_eq!("bright_red".parse(), Ok(Color::Ansi(AnsiColor::BrightRed)));
+/// assert_eq!("208".parse(), Ok(Color::Ansi256(208)));
+/// assert_eq!("#f80".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("rgb(255, 136, 0)".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+
+        if let Some(args) = strip_prefix_ignore_case(s, "rgb(") {
+            return parse_rgb_args(args);
+        }
+
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            return parse_u8(s)
+                .map(Color::Ansi256)
+                .ok_or(ParseColorError::InvalidIndex);
+        }
+
+        if name_eq(s, "default") {
+            return Ok(Color::Default);
+        }
+
+        s.parse().map(Color::Ansi)
+    }
+}
+
+/// Formats the color in a format that can be parsed by [`Color::from_str`].
+///
+/// RGB colors are formatted as `#rrggbb`.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::Color;
+///
+/// assert_eq!(Color::Rgb(255, 136, 0).to_string(), "#ff8800");
+/// assert_eq!(Color::Ansi256(208).to_string(), "208");
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Color::Default => f.write_str("default"),
+            Color::Ansi(color) => color.fmt(f),
+            Color::Ansi256(i) => i.fmt(f),
+            Color::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+        }
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Color, ParseColorError> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseColorError::InvalidHex);
+    }
+
+    let component = |i: usize, len: usize| {
+        u8::from_str_radix(&hex[i * len..(i + 1) * len], 16)
+            .map_err(|_| ParseColorError::InvalidHex)
+    };
+
+    match hex.len() {
+        // Each digit is repeated, so `#f80` is the same as `#ff8800`.
+        3 => Ok(Color::Rgb(
+            component(0, 1)? * 0x11,
+            component(1, 1)? * 0x11,
+            component(2, 1)? * 0x11,
+        )),
+        6 => Ok(Color::Rgb(
+            component(0, 2)?,
+            component(1, 2)?,
+            component(2, 2)?,
+        )),
+        _ => Err(ParseColorError::InvalidHex),
+    }
+}
+
+fn parse_rgb_args(args: &str) -> Result<Color, ParseColorError> {
+    let args = args.strip_suffix(')').ok_or(ParseColorError::InvalidRgb)?;
+
+    let mut components = args.split(',').map(|c| parse_u8(c.trim()));
+    let mut next = || {
+        components
+            .next()
+            .flatten()
+            .ok_or(ParseColorError::InvalidRgb)
+    };
+
+    let color = Color::Rgb(next()?, next()?, next()?);
+
+    match components.next() {
+        Some(_) => Err(ParseColorError::InvalidRgb),
+        None => Ok(color),
+    }
+}
+
+/// Parse a decimal number from 0 to 255, which unlike [`u8::from_str`] doesn't accept
+/// a leading `+`.
+fn parse_u8(s: &str) -> Option<u8> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+/// Compare an input to a lowercase name, ignoring case and treating `-` as `_`.
+pub(crate) fn name_eq(input: &str, name: &str) -> bool {
+    input.len() == name.len()
+        && input.bytes().zip(name.bytes()).all(|(a, b)| {
+            let a = if a == b'-' {
+                b'_'
+            } else {
+                a.to_ascii_lowercase()
+            };
+            a == b
+        })
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+const ATTRIBUTE_NAMES: [(Attributes, &str); 15] = [
+    (Attributes::BOLD, "bold"),
+    (Attributes::DIM, "dim"),
+    (Attributes::ITALIC, "italic"),
+    (Attributes::UNDERLINED, "underlined"),
+    (Attributes::BLINKING, "blinking"),
+    (Attributes::INVERTED, "inverted"),
+    (Attributes::HIDDEN, "hidden"),
+    (Attributes::CROSSED, "crossed"),
+    (Attributes::RAPID_BLINKING, "rapid_blinking"),
+    (Attributes::DOUBLE_UNDERLINED, "double_underlined"),
+    (Attributes::FRAMED, "framed"),
+    (Attributes::ENCIRCLED, "encircled"),
+    (Attributes::OVERLINED, "overlined"),
+    (Attributes::SUPERSCRIPT, "superscript"),
+    (Attributes::SUBSCRIPT, "subscript"),
+];
+
+// Alternative names, mostly matching git's color configuration.
+const ATTRIBUTE_ALIASES: [(Attributes, &str); 10] = [
+    (Attributes::UNDERLINED, "underline"),
+    (Attributes::UNDERLINED, "ul"),
+    (Attributes::BLINKING, "blink"),
+    (Attributes::INVERTED, "reverse"),
+    (Attributes::CROSSED, "strike"),
+    (Attributes::CROSSED, "strikethrough"),
+    (Attributes::HIDDEN, "conceal"),
+    (Attributes::RAPID_BLINKING, "rapid_blink"),
+    (Attributes::DOUBLE_UNDERLINED, "double_underline"),
+    (Attributes::OVERLINED, "overline"),
+];
+
+const UNDERLINE_STYLE_NAMES: [(UnderlineStyle, &str); 3] = [
+    (UnderlineStyle::Curly, "curly_underlined"),
+    (UnderlineStyle::Dotted, "dotted_underlined"),
+    (UnderlineStyle::Dashed, "dashed_underlined"),
+];
+
+/// An error returned when parsing a [`Style`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseStyleError {
+    kind: ParseStyleErrorKind,
+    position: usize,
+}
+
+impl ParseStyleError {
+    /// The kind of error.
+    #[inline]
+    pub const fn kind(&self) -> ParseStyleErrorKind {
+        self.kind
+    }
+
+    /// The byte offset of the word that caused the error.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// The kind of a [`ParseStyleError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseStyleErrorKind {
+    /// A word wasn't an attribute or a valid color.
+    InvalidColor(ParseColorError),
+
+    /// `on` wasn't followed by a color.
+    ExpectedBackground,
+
+    /// `underline_color` wasn't followed by a color.
+    ExpectedUnderlineColor,
+
+    /// More than two colors were given, or the background color was given twice.
+    TooManyColors,
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseStyleErrorKind::InvalidColor(err) => write!(f, "{err}")?,
+            ParseStyleErrorKind::ExpectedBackground => {
+                f.write_str("expected a color after `on`")?
+            }
+            ParseStyleErrorKind::ExpectedUnderlineColor => {
+                f.write_str("expected a color after `underline_color`")?
+            }
+            ParseStyleErrorKind::TooManyColors => f.write_str("too many colors")?,
+        }
+
+        write!(f, " at position {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseStyleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseStyleErrorKind::InvalidColor(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Style {
+    /// Parse a style specification, such as `bold italic #ff8800 on bright_black`.
+    ///
+    /// A specification is a whitespace-separated list of words, which are applied in order:
+    /// - Attributes, named after the corresponding builder methods (such as `bold` or
+    ///   `crossed`), or one of the aliases `underline`, `ul`, `double_underline`, `blink`,
+    ///   `rapid_blink`, `reverse`, `strike`, `strikethrough`, `conceal` and `overline`.
+    /// - Underline styles: `curly_underlined`, `dotted_underlined` and `dashed_underlined`.
+    /// - Negated attributes, such as `nobold` or `no-bold`, which remove an attribute
+    ///   that was set by an earlier word.
+    /// - Colors (see [`Color::from_str`]). The first color sets the foreground color,
+    ///   and the second color sets the background color.
+    /// - `on` followed by a color, which sets the background color.
+    /// - `underline_color` followed by a color, which sets the underline color.
+    ///
+    /// Setting the foreground or background color more than once is an error. Words are
+    /// case-insensitive, and `-` may be used instead of `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::Style;
+    ///
+    /// assert_eq!(
+    ///     Style::parse("bold italic #ff8800 on bright_black"),
+    ///     Ok(Style::new().bold().italic().rgb_color(255, 136, 0).on_bright_black()),
+    /// );
+    ///
+    /// let err = Style::parse("bold purple").unwrap_err();
+    /// assert_eq!(err.position(), 5);
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self, ParseStyleError> {
+        let mut style = Style::new();
+        let mut has_fg = false;
+        let mut has_bg = false;
+
+        let mut words = Words { spec, position: 0 };
+
+        while let Some((position, word)) = words.next() {
+            let err = |kind| ParseStyleError { kind, position };
+
+            if let Some(attributes) = parse_attribute(word) {
+                style.attributes |= attributes;
+                continue;
+            }
+
+            if let Some(attributes) = parse_negated_attribute(word) {
+                style.attributes &= !attributes;
+
+                // The underline style only applies while the text is underlined.
+                if attributes.contains(Attributes::UNDERLINED) {
+                    style.underline_style = UnderlineStyle::Single;
+                }
+
+                continue;
+            }
+
+            if let Some((underline_style, _)) = UNDERLINE_STYLE_NAMES
+                .iter()
+                .find(|(_, name)| name_eq(word, name))
+            {
+                style = style.underline_style(*underline_style);
+                continue;
+            }
+
+            if name_eq(word, "underline_color") {
+                let (position, word) = words
+                    .next()
+                    .ok_or(err(ParseStyleErrorKind::ExpectedUnderlineColor))?;
+
+                style.underline_color = word.parse().map_err(|color_err| ParseStyleError {
+                    kind: ParseStyleErrorKind::InvalidColor(color_err),
+                    position,
+                })?;
+
+                continue;
+            }
+
+            if name_eq(word, "on") {
+                let (position, word) = words
+                    .next()
+                    .ok_or(err(ParseStyleErrorKind::ExpectedBackground))?;
+
+                style.bg = word.parse().map_err(|color_err| ParseStyleError {
+                    kind: ParseStyleErrorKind::InvalidColor(color_err),
+                    position,
+                })?;
+
+                if has_bg {
+                    return Err(ParseStyleError {
+                        kind: ParseStyleErrorKind::TooManyColors,
+                        position,
+                    });
+                }
+                has_bg = true;
+
+                continue;
+            }
+
+            let color = word
+                .parse()
+                .map_err(|color_err| err(ParseStyleErrorKind::InvalidColor(color_err)))?;
+
+            if !has_fg {
+                style.fg = color;
+                has_fg = true;
+            } else if !has_bg {
+                style.bg = color;
+                has_bg = true;
+            } else {
+                return Err(err(ParseStyleErrorKind::TooManyColors));
+            }
+        }
+
+        Ok(style)
+    }
+
+    /// Returns a value that formats the style as a specification that can be parsed by
+    /// [`Style::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::Style;
+    ///
+    /// let style = Style::new().bold().rgb_color(255, 136, 0).on_bright_black();
+    /// assert_eq!(style.to_spec().to_string(), "bold #ff8800 on bright_black");
+    /// assert_eq!(Style::parse(&style.to_spec().to_string()), Ok(style));
+    /// ```
+    #[inline]
+    pub const fn to_spec(self) -> StyleSpec {
+        StyleSpec(self)
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    /// See [`Style::parse`].
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Formats a [`Style`] as a specification. See [`Style::to_spec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleSpec(Style);
+
+impl fmt::Display for StyleSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = self.0;
+
+        let mut separator = "";
+        let mut word = |f: &mut fmt::Formatter, word: &dyn fmt::Display| {
+            let result = write!(f, "{separator}{word}");
+            separator = " ";
+            result
+        };
+
+        for (attribute, name) in ATTRIBUTE_NAMES {
+            if !style.attributes.contains(attribute) {
+                continue;
+            }
+
+            let styled_underline = UNDERLINE_STYLE_NAMES
+                .iter()
+                .find(|(underline_style, _)| *underline_style == style.underline_style);
+
+            match styled_underline {
+                Some((_, name)) if attribute == Attributes::UNDERLINED => word(f, name)?,
+                _ => word(f, &name)?,
+            }
+        }
+
+        if style.fg != Color::Default {
+            word(f, &style.fg)?;
+        }
+
+        if style.bg != Color::Default {
+            word(f, &format_args!("on {}", style.bg))?;
+        }
+
+        if style.underline_color != Color::Default {
+            word(
+                f,
+                &format_args!("underline_color {}", style.underline_color),
+            )?;
+        }
+
+        if separator.is_empty() {
+            f.write_str("default")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_attribute(word: &str) -> Option<Attributes> {
+    ATTRIBUTE_NAMES
+        .iter()
+        .chain(ATTRIBUTE_ALIASES.iter())
+        .find(|(_, name)| name_eq(word, name))
+        .map(|(attributes, _)| *attributes)
+}
+
+fn parse_negated_attribute(word: &str) -> Option<Attributes> {
+    let word = strip_prefix_ignore_case(word, "no")?;
+    let word = word
+        .strip_prefix('-')
+        .or_else(|| word.strip_prefix('_'))
+        .unwrap_or(word);
+
+    parse_attribute(word)
+}
+
+/// Splits a specification into words, keeping parenthesized groups such as
+/// `rgb(1, 2, 3)` together.
+struct Words<'a> {
+    spec: &'a str,
+    position: usize,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.spec[self.position..];
+        let start = self.position + (rest.len() - rest.trim_start().len());
+
+        let mut in_parens = false;
+        let len = self.spec[start..]
+            .find(|c: char| {
+                match c {
+                    '(' => in_parens = true,
+                    ')' => in_parens = false,
+                    _ => {}
+                }
+                c.is_whitespace() && !in_parens
+            })
+            .unwrap_or(self.spec.len() - start);
+
+        self.position = start + len;
+
+        (len > 0).then(|| (start, &self.spec[start..start + len]))
+    }
+}
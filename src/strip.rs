@@ -0,0 +1,145 @@
+use core::fmt;
+
+use crate::visible::EscapeState;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+/// Remove all escape sequences from a string, such as the styles and hyperlinks written
+/// by this crate.
+///
+/// Control sequences (`ESC [`), operating system commands (`ESC ]`, including OSC 8
+/// hyperlinks) and other escape sequences are removed. The string is only copied if it
+/// contains an escape sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{strip_ansi, Stylize};
+///
+/// let styled = format!("{}", "Hello".bold().red());
+/// assert_eq!(strip_ansi(&styled), "Hello");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_ansi(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut writer = StripWriter::new(String::with_capacity(s.len()));
+    let _ = fmt::Write::write_str(&mut writer, s);
+
+    Cow::Owned(writer.into_inner())
+}
+
+/// A writer that removes escape sequences from everything written to it, before
+/// passing it on to the inner writer.
+///
+/// This implements [`fmt::Write`] and [`std::io::Write`] if the inner writer does.
+/// Escape sequences may be split across several writes, so it can be used to clean
+/// up a stream that is written in chunks, such as a log file.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::fmt::Write;
+///
+/// use stylic::{StripWriter, Stylize};
+///
+/// let mut writer = StripWriter::new(String::new());
+/// writeln!(writer, "{} world", "Hello".bold()).unwrap();
+/// assert_eq!(writer.into_inner(), "Hello world\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StripWriter<W> {
+    inner: W,
+    state: EscapeState,
+}
+
+impl<W> StripWriter<W> {
+    /// Wrap a writer.
+    #[inline]
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            state: EscapeState::Text,
+        }
+    }
+
+    /// Get a reference to the inner writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner writer.
+    ///
+    /// If an escape sequence was left unfinished, the rest of it is never written.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for StripWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // The start of the visible text that hasn't been written yet.
+        let mut start = 0;
+
+        for (i, c) in s.char_indices() {
+            if !self.state.advance(c) {
+                if start < i {
+                    self.inner.write_str(&s[start..i])?;
+                }
+                start = i + c.len_utf8();
+            }
+        }
+
+        if start < s.len() {
+            self.inner.write_str(&s[start..])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for StripWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        // Escape sequences are ASCII, and the bytes of a multi-byte character are never
+        // part of one, so the bytes can be handled one at a time even if a character is
+        // split across writes.
+        let mut start = 0;
+
+        for (i, &b) in buf.iter().enumerate() {
+            if !self.state.advance(b as char) {
+                if start < i {
+                    self.inner.write_all(&buf[start..i])?;
+                }
+                start = i + 1;
+            }
+        }
+
+        if start < buf.len() {
+            self.inner.write_all(&buf[start..])?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -1,88 +1,235 @@
 use core::{
     fmt,
+    num::TryFromIntError,
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not},
 };
 
 use crate::color::{impl_color_builder_methods, Color};
+use crate::{StaticStyle, Stream, Truncated};
 
+// Like `impl_color_builder_methods`, the methods can be generated with any qualifiers,
+// receiver and return type. Each method that sets a field is given an expression, and
+// the rest are implemented in terms of those, using the second receiver, which doesn't
+// need to be mutable.
 macro_rules! impl_style_builder_methods {
     ($self:ident => $style:expr) => {
-        impl_color_builder_methods!($self, color =>
-            {
-                $style.fg = color;
-                $self
-            },
-            {
-                $style.bg = color;
-                $self
+        impl_style_builder_methods! {
+            [pub const] (mut $self: Self) ($self: Self) -> Self;
+            $self, value => {
+                fg: {
+                    $style.fg = value;
+                    $self
+                },
+                bg: {
+                    $style.bg = value;
+                    $self
+                },
+                attributes: {
+                    $style.attributes = $style.attributes.or(value);
+                    $self
+                },
+                underline_style: {
+                    $style.underline_style = value;
+                    $self.attributes(Attributes::UNDERLINED)
+                },
+                underline_color: {
+                    $style.underline_color = value;
+                    $self
+                },
+            }
+        }
+    };
+
+    (
+        $qualifiers:tt $receiver:tt $plain_receiver:tt -> $output:ty;
+        $self:ident, $value:ident => {
+            fg: $fg:expr,
+            bg: $bg:expr,
+            attributes: $attributes:expr,
+            underline_style: $underline_style:expr,
+            underline_color: $underline_color:expr $(,)?
+        }
+    ) => {
+        impl_color_builder_methods!($qualifiers $receiver -> $output; $value => $fg, $bg);
+
+        $crate::color::builder_method! {
+            /// Set the attributes. See [`Attributes`].
+            $qualifiers fn attributes $receiver ($value: Attributes) -> $output {
+                $attributes
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the bold attribute.
+            $qualifiers fn bold $plain_receiver () -> $output {
+                $self.attributes(Attributes::BOLD)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the dim attribute.
+            $qualifiers fn dim $plain_receiver () -> $output {
+                $self.attributes(Attributes::DIM)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the italic attribute.
+            $qualifiers fn italic $plain_receiver () -> $output {
+                $self.attributes(Attributes::ITALIC)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the underlined attribute.
+            $qualifiers fn underlined $plain_receiver () -> $output {
+                $self.attributes(Attributes::UNDERLINED)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the blinking attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn blinking $plain_receiver () -> $output {
+                $self.attributes(Attributes::BLINKING)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the inverted attribute.
+            ///
+            /// Inverts the foreground and background colors.
+            $qualifiers fn inverted $plain_receiver () -> $output {
+                $self.attributes(Attributes::INVERTED)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the hidden attribute.
+            ///
+            /// Makes the text invisible.
+            $qualifiers fn hidden $plain_receiver () -> $output {
+                $self.attributes(Attributes::HIDDEN)
             }
-        );
+        }
 
-        /// Set the attributes. See [`Attributes`].
-        #[inline]
-        pub const fn attributes(mut $self: Self, attributes: Attributes) -> Self {
-            $style.attributes = $style.attributes.or(attributes);
-            $self
+        $crate::color::builder_method! {
+            /// Set the crossed attribute.
+            ///
+            /// Makes the text struck through.
+            $qualifiers fn crossed $plain_receiver () -> $output {
+                $self.attributes(Attributes::CROSSED)
+            }
         }
 
-        /// Set the bold attribute.
-        #[inline]
-        pub const fn bold(self) -> Self {
-            self.attributes(Attributes::BOLD)
+        $crate::color::builder_method! {
+            /// Set the rapid blinking attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn rapid_blinking $plain_receiver () -> $output {
+                $self.attributes(Attributes::RAPID_BLINKING)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the double underlined attribute.
+            ///
+            /// Some terminals treat this as turning off bold instead.
+            $qualifiers fn double_underlined $plain_receiver () -> $output {
+                $self.attributes(Attributes::DOUBLE_UNDERLINED)
+            }
         }
 
-        /// Set the dim attribute.
-        #[inline]
-        pub const fn dim(self) -> Self {
-            self.attributes(Attributes::DIM)
+        $crate::color::builder_method! {
+            /// Set the framed attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn framed $plain_receiver () -> $output {
+                $self.attributes(Attributes::FRAMED)
+            }
         }
 
-        /// Set the italic attribute.
-        #[inline]
-        pub const fn italic(self) -> Self {
-            self.attributes(Attributes::ITALIC)
+        $crate::color::builder_method! {
+            /// Set the encircled attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn encircled $plain_receiver () -> $output {
+                $self.attributes(Attributes::ENCIRCLED)
+            }
         }
 
-        /// Set the underlined attribute.
-        #[inline]
-        pub const fn underlined(self) -> Self {
-            self.attributes(Attributes::UNDERLINED)
+        $crate::color::builder_method! {
+            /// Set the overlined attribute.
+            ///
+            /// Draws a line above the text.
+            $qualifiers fn overlined $plain_receiver () -> $output {
+                $self.attributes(Attributes::OVERLINED)
+            }
         }
 
-        /// Set the blinking attribute.
-        ///
-        /// Not supported by many terminals.
-        #[inline]
-        pub const fn blinking(self) -> Self {
-            self.attributes(Attributes::BLINKING)
+        $crate::color::builder_method! {
+            /// Set the superscript attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn superscript $plain_receiver () -> $output {
+                $self.attributes(Attributes::SUPERSCRIPT)
+            }
         }
 
-        /// Set the inverted attribute.
-        ///
-        /// Inverts the foreground and background colors.
-        #[inline]
-        pub const fn inverted(self) -> Self {
-            self.attributes(Attributes::INVERTED)
+        $crate::color::builder_method! {
+            /// Set the subscript attribute.
+            ///
+            /// Not supported by many terminals.
+            $qualifiers fn subscript $plain_receiver () -> $output {
+                $self.attributes(Attributes::SUBSCRIPT)
+            }
         }
 
-        /// Set the hidden attribute.
-        ///
-        /// Makes the text invisible.
-        #[inline]
-        pub const fn hidden(self) -> Self {
-            self.attributes(Attributes::HIDDEN)
+        $crate::color::builder_method! {
+            /// Set the underline style, and set the underlined attribute.
+            ///
+            /// See [`UnderlineStyle`] for which terminals support this.
+            $qualifiers fn underline_style $receiver ($value: UnderlineStyle) -> $output {
+                $underline_style
+            }
         }
 
-        /// Set the crossed attribute.
-        ///
-        /// Makes the text struck through.
-        #[inline]
-        pub const fn crossed(self) -> Self {
-            self.attributes(Attributes::CROSSED)
+        $crate::color::builder_method! {
+            /// Set a curly underline. See [`UnderlineStyle::Curly`].
+            $qualifiers fn curly_underlined $plain_receiver () -> $output {
+                $self.underline_style(UnderlineStyle::Curly)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set a dotted underline. See [`UnderlineStyle::Dotted`].
+            $qualifiers fn dotted_underlined $plain_receiver () -> $output {
+                $self.underline_style(UnderlineStyle::Dotted)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set a dashed underline. See [`UnderlineStyle::Dashed`].
+            $qualifiers fn dashed_underlined $plain_receiver () -> $output {
+                $self.underline_style(UnderlineStyle::Dashed)
+            }
+        }
+
+        $crate::color::builder_method! {
+            /// Set the underline color.
+            ///
+            /// This doesn't enable underlining on its own. Like [`UnderlineStyle`], this is
+            /// only written if RGB colors are supported.
+            $qualifiers fn underline_color $receiver ($value: Color) -> $output {
+                $underline_color
+            }
         }
     };
 }
 
+pub(crate) use impl_style_builder_methods;
+
 /// A style that can be applied to a value.
 ///
 /// Styles can be written directly (since they implement [`fmt::Display`]) or
@@ -98,6 +245,12 @@ pub struct Style {
 
     /// Attributes (for example, bold, italic, strikethrough).
     pub attributes: Attributes,
+
+    /// Underline style, used if the text is underlined.
+    pub underline_style: UnderlineStyle,
+
+    /// Underline color.
+    pub underline_color: Color,
 }
 
 impl Style {
@@ -108,18 +261,129 @@ impl Style {
             fg: Color::Default,
             bg: Color::Default,
             attributes: Attributes::EMPTY,
+            underline_style: UnderlineStyle::Single,
+            underline_color: Color::Default,
         }
     }
 
     impl_style_builder_methods!(self => self);
 
+    /// Layer a style on top of this one.
+    ///
+    /// Colors that are set in `over` replace the colors in `self`, while colors that
+    /// are [`Color::Default`] are taken from `self`. Attributes from both styles are
+    /// combined.
+    ///
+    /// This is how nested values that [inherit](Styled::inherit) are combined with the
+    /// enclosing style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::Style;
+    ///
+    /// const BASE: Style = Style::new().red().on_black();
+    /// const EMPHASIS: Style = BASE.layer(Style::new().bold().yellow());
+    ///
+    /// assert_eq!(EMPHASIS, Style::new().bold().yellow().on_black());
+    /// ```
+    #[inline]
+    pub const fn layer(self, over: Style) -> Style {
+        Style {
+            fg: match over.fg {
+                Color::Default => self.fg,
+                color => color,
+            },
+            bg: match over.bg {
+                Color::Default => self.bg,
+                color => color,
+            },
+            attributes: self.attributes.or(over.attributes),
+            underline_style: if over.attributes.contains(Attributes::UNDERLINED) {
+                over.underline_style
+            } else {
+                self.underline_style
+            },
+            underline_color: match over.underline_color {
+                Color::Default => self.underline_color,
+                color => color,
+            },
+        }
+    }
+
     /// Check if the style is the default style.
     #[inline]
     pub const fn is_default(&self) -> bool {
         matches!(self.fg, Color::Default)
             && matches!(self.bg, Color::Default)
+            && matches!(self.underline_color, Color::Default)
             && self.attributes.is_empty()
     }
+
+    /// Returns the style if `condition` is true, or the default style otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{apply, Style};
+    ///
+    /// let highlight = false;
+    /// let style = Style::new().yellow().when(highlight);
+    ///
+    /// assert_eq!(style, Style::new());
+    /// println!("{}", apply!(style => "maybe highlighted"));
+    /// ```
+    #[inline]
+    pub const fn when(self, condition: bool) -> Self {
+        if condition {
+            self
+        } else {
+            Self::new()
+        }
+    }
+
+    /// Returns a value that writes an escape sequence that changes from this style to
+    /// `next`.
+    ///
+    /// Writing a style directly resets all styling before applying the style. A
+    /// transition instead only writes the parameters that change, such as `22` to turn off
+    /// bold or `39` to reset the foreground color, which is useful for writing many
+    /// differently styled spans in a row. Nothing is written if the styles are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{set_style_mode, Style, StyleMode};
+    ///
+    /// set_style_mode(StyleMode::enable());
+    ///
+    /// let from = Style::new().bold().red();
+    /// let to = Style::new().red().italic();
+    /// assert_eq!(from.transition_to(to).to_string(), "\x1b[22;3m");
+    /// ```
+    #[inline]
+    pub const fn transition_to(&self, next: Style) -> StyleTransition {
+        StyleTransition {
+            from: *self,
+            to: next,
+        }
+    }
+}
+
+/// A transition between two styles. See [`Style::transition_to`].
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyleTransition {
+    pub from: Style,
+    pub to: Style,
+}
+
+impl From<Option<Style>> for Style {
+    /// Returns the style, or the default style if it is `None`.
+    #[inline]
+    fn from(style: Option<Style>) -> Self {
+        style.unwrap_or_default()
+    }
 }
 
 impl From<Attributes> for Style {
@@ -129,10 +393,43 @@ impl From<Attributes> for Style {
     }
 }
 
+/// The style of an underline.
+///
+/// Styled underlines are supported by many modern terminals, such as kitty,
+/// WezTerm, foot and VTE-based terminals. Since they can't be detected reliably,
+/// they are only written if RGB colors are supported, and otherwise fall back to a
+/// plain underline. The same applies to underline colors.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnderlineStyle {
+    /// A plain, single underline.
+    #[default]
+    Single,
+
+    /// A curly underline, often used for spell-checking or diagnostics.
+    Curly,
+
+    /// A dotted underline.
+    Dotted,
+
+    /// A dashed underline.
+    Dashed,
+}
+
 /// A styled value.
 ///
 /// Can be created using the [`styled!`](crate::styled) macro, which supports
 /// formatting arguments, or directly with [`Styled::new`].
+///
+/// Formatting options such as width, alignment and precision apply to the content.
+/// Padding with an explicit alignment is measured by visible width, so escape
+/// sequences aren't counted and wide characters count as two columns:
+///
+/// ```rust
+/// use stylic::{styled, Styled};
+///
+/// println!("[{:>8}]", styled!("ok").green());
+/// println!("[{:05}]", Styled::new(42).bold());
+/// ```
 #[allow(missing_docs)]
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Styled<T> {
@@ -159,9 +456,128 @@ impl<T> Styled<T> {
         self
     }
 
+    /// [Layer](Style::layer) a style on top of the current one if `condition` is true,
+    /// and otherwise leave it unchanged.
+    ///
+    /// This keeps the type the same either way, so it can be used to decide on a
+    /// style inline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{styled, Style};
+    ///
+    /// const ERROR: Style = Style::new().red();
+    ///
+    /// // Printed bold, and also red if the tests failed.
+    /// let failed = true;
+    /// println!("{}", styled!("{} tests", 3).bold().style_if(failed, ERROR));
+    /// ```
+    #[inline]
+    pub const fn style_if(mut self, condition: bool, style: Style) -> Self {
+        if condition {
+            self.style = self.style.layer(style);
+        }
+        self
+    }
+
+    /// [Layer](Style::layer) a style on top of the current one if it is `Some`, and
+    /// otherwise leave it unchanged.
+    #[inline]
+    pub const fn maybe_style(mut self, style: Option<Style>) -> Self {
+        if let Some(style) = style {
+            self.style = self.style.layer(style);
+        }
+        self
+    }
+
     impl_style_builder_methods!(self => self.style);
+
+    /// Render the styled value for the given stream, using its style mode.
+    #[inline]
+    pub const fn for_stream(self, stream: Stream) -> ForStream<Self> {
+        ForStream {
+            value: self,
+            stream,
+        }
+    }
+
+    /// Inherit any colors and attributes that the style doesn't set from the enclosing
+    /// style when nested, rather than replacing it. See [`Inherit`].
+    #[inline]
+    pub const fn inherit(self) -> Inherit<Self> {
+        Inherit { value: self }
+    }
+
+    /// Style the [`Debug`](core::fmt::Debug) representation of the content, rather
+    /// than its [`Display`](core::fmt::Display) representation.
+    ///
+    /// Formatting the returned value with `{}` formats the content with `{:?}`, and
+    /// `{:#}` formats it with `{:#?}`. The derived `Debug` implementation of [`Styled`]
+    /// itself is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::Styled;
+    ///
+    /// println!("{}", Styled::new(Some("value")).yellow().debug());
+    /// println!("{:#}", Styled::new(["a", "b"]).yellow().debug());
+    /// ```
+    #[inline]
+    pub fn debug(self) -> Styled<AsDebug<T>> {
+        Styled {
+            content: AsDebug(self.content),
+            style: self.style,
+        }
+    }
+}
+
+impl<T: fmt::Display> Styled<T> {
+    /// The number of terminal columns the content takes up when displayed.
+    ///
+    /// Unlike the length of the rendered string, this doesn't count escape sequences,
+    /// and accounts for wide characters such as CJK characters and emoji. See
+    /// [`WidthCounter`](crate::WidthCounter).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::styled;
+    ///
+    /// assert_eq!(styled!("{}", "日本語").bold().visible_width(), 6);
+    /// ```
+    #[inline]
+    pub fn visible_width(&self) -> usize {
+        crate::width::display_width(&self.content)
+    }
+
+    /// Truncate the styled value to fit `width` columns when displayed, ending it with
+    /// `ellipsis` if it doesn't fit. See [`Truncated`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::fmt::Write;
+    ///
+    /// use stylic::{StripWriter, Styled};
+    ///
+    /// let status = Styled::new("downloading dependencies").green();
+    /// let mut plain = StripWriter::new(String::new());
+    /// write!(plain, "{}", status.truncate(10, "…")).unwrap();
+    /// assert_eq!(plain.into_inner(), "downloadi…");
+    /// ```
+    #[inline]
+    pub const fn truncate<E: fmt::Display>(self, width: usize, ellipsis: E) -> Truncated<Self, E> {
+        Truncated::new(self, width, ellipsis)
+    }
 }
 
+/// Displays a value using its [`Debug`](core::fmt::Debug) implementation. See
+/// [`Styled::debug`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsDebug<T>(pub T);
+
 /// A styled hyperlink.
 ///
 /// Can be created using the [`hyperlink!`](crate::hyperlink) macro, which supports
@@ -195,12 +611,139 @@ impl<U, T> Hyperlink<U, T> {
         self
     }
 
+    /// [Layer](Style::layer) a style on top of the current one if `condition` is true,
+    /// and otherwise leave it unchanged.
+    ///
+    /// This keeps the type the same either way, so it can be used to decide on a
+    /// style inline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylic::{hyperlink, Style};
+    ///
+    /// let visited = false;
+    /// println!(
+    ///     "{}",
+    ///     hyperlink!("https://rust-lang.org"; "Rust").style_if(visited, Style::new().magenta())
+    /// );
+    /// ```
+    #[inline]
+    pub const fn style_if(mut self, condition: bool, style: Style) -> Self {
+        if condition {
+            self.style = self.style.layer(style);
+        }
+        self
+    }
+
+    /// [Layer](Style::layer) a style on top of the current one if it is `Some`, and
+    /// otherwise leave it unchanged.
+    #[inline]
+    pub const fn maybe_style(mut self, style: Option<Style>) -> Self {
+        if let Some(style) = style {
+            self.style = self.style.layer(style);
+        }
+        self
+    }
+
     impl_style_builder_methods!(self => self.style);
+
+    /// Render the hyperlink for the given stream, using its style mode.
+    #[inline]
+    pub const fn for_stream(self, stream: Stream) -> ForStream<Self> {
+        ForStream {
+            value: self,
+            stream,
+        }
+    }
+
+    /// Inherit any colors and attributes that the style doesn't set from the enclosing
+    /// style when nested, rather than replacing it. See [`Inherit`].
+    #[inline]
+    pub const fn inherit(self) -> Inherit<Self> {
+        Inherit { value: self }
+    }
+}
+
+impl<U, T: fmt::Display> Hyperlink<U, T> {
+    /// The number of terminal columns the content takes up when displayed. See
+    /// [`Styled::visible_width`].
+    #[inline]
+    pub fn visible_width(&self) -> usize {
+        crate::width::display_width(&self.content)
+    }
+
+    /// Truncate the hyperlink to fit `width` columns when displayed, ending it with
+    /// `ellipsis` if it doesn't fit. See [`Truncated`].
+    #[inline]
+    pub const fn truncate<E: fmt::Display>(self, width: usize, ellipsis: E) -> Truncated<Self, E> {
+        Truncated::new(self, width, ellipsis)
+    }
+}
+
+/// A value styled with a [`StaticStyle`], whose escape sequences are generated at
+/// compile time.
+///
+/// Can be created using [`StaticStyle::apply`]. It is displayed the same way as a
+/// [`Styled`] value with the same style.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticStyled<T> {
+    pub content: T,
+    pub style: &'static StaticStyle,
+}
+
+impl<T> StaticStyled<T> {
+    /// Render the styled value for the given stream, using its style mode.
+    #[inline]
+    pub const fn for_stream(self, stream: Stream) -> ForStream<Self> {
+        ForStream {
+            value: self,
+            stream,
+        }
+    }
+}
+
+/// A value that is rendered for a specific [`Stream`].
+///
+/// Any styled values nested inside the value are also rendered for the stream.
+/// Can be created using [`Styled::for_stream`] or [`Hyperlink::for_stream`].
+///
+/// Without the `std` feature, the stream being rendered for is shared by the whole
+/// program rather than tracked per thread, so values shouldn't be rendered for
+/// different streams at the same time.
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ForStream<T> {
+    pub value: T,
+    pub stream: Stream,
+}
+
+/// A styled value or hyperlink that inherits from the enclosing style when nested.
+///
+/// Its style is [layered](Style::layer) on top of the style of the enclosing styled
+/// value, so any colors and attributes that it doesn't set are kept. Requires the
+/// `nested_styles` feature, since otherwise the enclosing style isn't known.
+///
+/// Can be created using [`Styled::inherit`] or [`Hyperlink::inherit`].
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::styled;
+///
+/// // With `nested_styles`, "world" is printed bold and red.
+/// println!("{}", styled!("hello {}", styled!("world").bold().inherit()).red());
+/// ```
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Inherit<T> {
+    pub value: T,
 }
 
 /// A set of attributes (bold, italic, etc).
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Attributes(u8);
+pub struct Attributes(u16);
 
 impl Attributes {
     /// An empty set of attributes.
@@ -230,8 +773,32 @@ impl Attributes {
     /// An attribute set that enables crossed out text. See [`Style::crossed`].
     pub const CROSSED: Self = Self(1 << 7);
 
+    /// An attribute set that enables rapidly blinking text. See [`Style::rapid_blinking`].
+    pub const RAPID_BLINKING: Self = Self(1 << 8);
+
+    /// An attribute set that enables double underlined text. See [`Style::double_underlined`].
+    pub const DOUBLE_UNDERLINED: Self = Self(1 << 9);
+
+    /// An attribute set that enables framed text. See [`Style::framed`].
+    pub const FRAMED: Self = Self(1 << 10);
+
+    /// An attribute set that enables encircled text. See [`Style::encircled`].
+    pub const ENCIRCLED: Self = Self(1 << 11);
+
+    /// An attribute set that enables overlined text. See [`Style::overlined`].
+    pub const OVERLINED: Self = Self(1 << 12);
+
+    /// An attribute set that enables superscript text. See [`Style::superscript`].
+    pub const SUPERSCRIPT: Self = Self(1 << 13);
+
+    /// An attribute set that enables subscript text. See [`Style::subscript`].
+    pub const SUBSCRIPT: Self = Self(1 << 14);
+
+    // Every defined attribute, which negation is limited to.
+    const ALL: Self = Self((1 << 15) - 1);
+
     #[inline]
-    pub(crate) const fn into_bits(self) -> u8 {
+    pub(crate) const fn into_bits(self) -> u16 {
         self.0
     }
 
@@ -262,7 +829,7 @@ impl Attributes {
     /// Return a set of attributes containing all the attributes NOT in `self`.
     #[inline]
     pub const fn not(self) -> Self {
-        Self(!self.0)
+        Self(!self.0 & Self::ALL.0)
     }
 }
 
@@ -289,6 +856,13 @@ impl fmt::Debug for Attributes {
             INVERTED
             HIDDEN
             CROSSED
+            RAPID_BLINKING
+            DOUBLE_UNDERLINED
+            FRAMED
+            ENCIRCLED
+            OVERLINED
+            SUPERSCRIPT
+            SUBSCRIPT
         }
 
         f.finish()
@@ -332,13 +906,27 @@ impl Not for Attributes {
 
     #[inline]
     fn not(self) -> Self::Output {
-        Self(!self.0)
+        Attributes::not(self)
     }
 }
 
-impl From<Attributes> for u8 {
+impl From<Attributes> for u16 {
     #[inline]
     fn from(value: Attributes) -> Self {
         value.0
     }
 }
+
+/// Converts attributes to the eight bits that were used before the attributes from
+/// [`Attributes::RAPID_BLINKING`] onwards were added.
+///
+/// Fails if any of the newer attributes are set. Use the conversion to `u16` to get
+/// every attribute.
+impl TryFrom<Attributes> for u8 {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(value: Attributes) -> Result<Self, Self::Error> {
+        u8::try_from(value.0)
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt;
+
+use crate::color::{impl_color_builder_methods, Color};
+use crate::style::{impl_style_builder_methods, Attributes, Styled, UnderlineStyle};
+
+/// An extension trait for styling any value that implements [`Display`](fmt::Display).
+///
+/// This provides the same builder methods as [`Style`](crate::Style), each of which
+/// borrows the value and returns a [`Styled`] value. Since the method names are common,
+/// the trait isn't used unless it is imported.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::Stylize;
+///
+/// let name = "World";
+/// println!("Hello, {}!", name.bold().red());
+/// println!("{} items", 42.cyan());
+/// ```
+pub trait Stylize: fmt::Display {
+    impl_style_builder_methods! {
+        [] (&self) (&self) -> Styled<&Self>;
+        self, value => {
+            fg: Styled::new(self).fg(value),
+            bg: Styled::new(self).bg(value),
+            attributes: Styled::new(self).attributes(value),
+            underline_style: Styled::new(self).underline_style(value),
+            underline_color: Styled::new(self).underline_color(value),
+        }
+    }
+}
+
+impl<T: fmt::Display + ?Sized> Stylize for T {}
@@ -0,0 +1,232 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+use core::ops::{Add, AddAssign};
+use core::slice;
+
+use crate::macros::FormatArgsCallback;
+use crate::sgr::transition_sgr;
+use crate::{ColorLevel, Style, Styled, Truncated};
+
+/// Owned text made up of several spans, each with its own style.
+///
+/// Unlike nesting [`styled!`](crate::styled) values, this can be built up piece by piece
+/// at runtime. Adjacent spans with the same style are merged, and empty spans are
+/// skipped. When displayed, only what changes between one span and the next is
+/// written (see [`Style::transition_to`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{Styled, StyledText};
+///
+/// let mut text = StyledText::from(Styled::new("error").red().bold());
+/// text.push(Styled::new(": "));
+/// text += Styled::new("file not found").italic();
+///
+/// println!("{text}");
+/// assert_eq!(text.spans().len(), 3);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StyledText {
+    spans: Vec<Styled<String>>,
+}
+
+impl StyledText {
+    /// Create empty text.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { spans: Vec::new() }
+    }
+
+    /// Append a styled value, formatting its content.
+    ///
+    /// The content is formatted straight away, so any styled values nested in it are
+    /// rendered using the current style mode.
+    ///
+    /// Like [`ToString::to_string`], this expects formatting the content not to fail.
+    /// If it returns an error anyway, the content written up to that point is kept.
+    pub fn push<T: fmt::Display>(&mut self, value: Styled<T>) {
+        let mut content = String::new();
+        let _ = write!(content, "{}", value.content);
+
+        self.push_span(Styled {
+            content,
+            style: value.style,
+        });
+    }
+
+    fn push_span(&mut self, span: Styled<String>) {
+        if span.content.is_empty() {
+            return;
+        }
+
+        match self.spans.last_mut() {
+            Some(last) if last.style == span.style => last.content.push_str(&span.content),
+            _ => self.spans.push(span),
+        }
+    }
+
+    /// The spans that make up the text.
+    #[inline]
+    pub fn spans(&self) -> &[Styled<String>] {
+        &self.spans
+    }
+
+    /// Iterate over the spans that make up the text.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Styled<String>> {
+        self.spans.iter()
+    }
+
+    /// Whether the text is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The number of terminal columns the text takes up when displayed. See
+    /// [`Styled::visible_width`].
+    pub fn visible_width(&self) -> usize {
+        self.spans.iter().map(Styled::visible_width).sum()
+    }
+
+    /// Truncate the text to fit `width` columns when displayed, ending it with
+    /// `ellipsis` if it doesn't fit. See [`Truncated`].
+    #[inline]
+    pub fn truncate<E: fmt::Display>(&self, width: usize, ellipsis: E) -> Truncated<&Self, E> {
+        Truncated::new(self, width, ellipsis)
+    }
+}
+
+impl fmt::Display for StyledText {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Formatting options apply to the text as a whole.
+        FormatArgsCallback::new(|f: &mut fmt::Formatter| self.write(f)).fmt(f)
+    }
+}
+
+impl StyledText {
+    fn write(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let level = crate::current_color_level();
+
+        if level == ColorLevel::None {
+            return self
+                .spans
+                .iter()
+                .try_for_each(|span| f.write_str(&span.content));
+        }
+
+        // The style that is applied after the previous span, if it is known. The first
+        // span resets whatever came before it.
+        let mut current: Option<Style> = None;
+
+        for span in &self.spans {
+            // Content with escape sequences in it may change the style itself, so it is
+            // written as a styled value of its own, which is reset at the end.
+            if span.content.contains('\x1b') {
+                fmt::Display::fmt(span, f)?;
+                current = None;
+                continue;
+            }
+
+            match current {
+                Some(from) => f.write_str(&transition_sgr(from, span.style, level))?,
+                None => f.write_str(&span.style.sgr_at(level))?,
+            }
+
+            f.write_str(&span.content)?;
+            current = Some(span.style);
+        }
+
+        match current {
+            Some(style) if !style.is_default() => f.write_str("\x1b[0m"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: fmt::Display> From<Styled<T>> for StyledText {
+    #[inline]
+    fn from(value: Styled<T>) -> Self {
+        let mut text = Self::new();
+        text.push(value);
+        text
+    }
+}
+
+impl<T: fmt::Display> Extend<Styled<T>> for StyledText {
+    fn extend<I: IntoIterator<Item = Styled<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl Extend<StyledText> for StyledText {
+    fn extend<I: IntoIterator<Item = StyledText>>(&mut self, iter: I) {
+        for text in iter {
+            text.spans.into_iter().for_each(|span| self.push_span(span));
+        }
+    }
+}
+
+impl<T: fmt::Display> FromIterator<Styled<T>> for StyledText {
+    fn from_iter<I: IntoIterator<Item = Styled<T>>>(iter: I) -> Self {
+        let mut text = Self::new();
+        text.extend(iter);
+        text
+    }
+}
+
+impl<T: fmt::Display> AddAssign<Styled<T>> for StyledText {
+    #[inline]
+    fn add_assign(&mut self, rhs: Styled<T>) {
+        self.push(rhs);
+    }
+}
+
+impl AddAssign<StyledText> for StyledText {
+    #[inline]
+    fn add_assign(&mut self, rhs: StyledText) {
+        self.extend([rhs]);
+    }
+}
+
+impl<T: fmt::Display> Add<Styled<T>> for StyledText {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Styled<T>) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<StyledText> for StyledText {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: StyledText) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl IntoIterator for StyledText {
+    type Item = Styled<String>;
+    type IntoIter = alloc::vec::IntoIter<Styled<String>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.spans.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a StyledText {
+    type Item = &'a Styled<String>;
+    type IntoIter = slice::Iter<'a, Styled<String>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.spans.iter()
+    }
+}
@@ -0,0 +1,199 @@
+use core::fmt::{self, Write};
+
+use crate::macros::FormatArgsCallback;
+use crate::visible::EscapeState;
+use crate::width::{display_width, Segment, Segmenter};
+
+/// A value that is truncated to fit a maximum width when displayed.
+///
+/// If the value is wider than the maximum width, it is cut off at a grapheme cluster
+/// boundary and followed by the ellipsis, such that both together fit. If the ellipsis
+/// itself is too wide, it is truncated to fit instead. Widths are measured as by
+/// [`WidthCounter`](crate::WidthCounter).
+///
+/// Escape sequences are never cut in half. Any style or hyperlink that is open where the
+/// value is cut off is closed before the ellipsis, which can itself be styled.
+///
+/// Can be created using [`Styled::truncate`](crate::Styled::truncate),
+/// [`Hyperlink::truncate`](crate::Hyperlink::truncate), or directly with
+/// [`Truncated::new`], which can also be used to truncate text that already contains
+/// escape sequences.
+///
+/// # Examples
+///
+/// ```rust
+/// use stylic::{Styled, Stylize};
+///
+/// println!("{}", Styled::new("a long status message").bold().truncate(10, "…"));
+/// println!("{}", Styled::new("a long status message").truncate(10, "...".dim()));
+/// ```
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Truncated<T, E> {
+    pub value: T,
+    pub width: usize,
+    pub ellipsis: E,
+}
+
+impl<T, E> Truncated<T, E> {
+    /// Truncate a value to fit `width` columns, ending it with `ellipsis` if it
+    /// doesn't fit. Use an empty ellipsis to cut the value off without one.
+    #[inline]
+    pub const fn new(value: T, width: usize, ellipsis: E) -> Self {
+        Self {
+            value,
+            width,
+            ellipsis,
+        }
+    }
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for Truncated<T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Formatting options apply to the truncated value as a whole.
+        FormatArgsCallback::new(|f: &mut fmt::Formatter| self.write(f)).fmt(f)
+    }
+}
+
+impl<T: fmt::Display, E: fmt::Display> Truncated<T, E> {
+    fn write(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if display_width(&self.value) <= self.width {
+            return self.value.fmt(f);
+        }
+
+        let ellipsis_width = display_width(&self.ellipsis);
+        if ellipsis_width > self.width {
+            return write!(f, "{}", Truncated::new(&self.ellipsis, self.width, ""));
+        }
+
+        let mut writer = Truncator {
+            inner: f,
+            segmenter: Segmenter::new(),
+            open: OpenSequences::default(),
+            remaining: self.width - ellipsis_width,
+            cut: false,
+        };
+        write!(writer, "{}", self.value)?;
+
+        let open = writer.open;
+
+        if open.hyperlink {
+            f.write_str("\x1b]8;;\x1b\\")?;
+        }
+
+        // With `nested_styles`, an enclosing styled value restores its style after this.
+        if open.style {
+            f.write_str("\x1b[0m")?;
+        }
+
+        write!(f, "{}", self.ellipsis)
+    }
+}
+
+/// Writes visible text until it runs out of space, and then drops everything else.
+struct Truncator<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    segmenter: Segmenter,
+    open: OpenSequences,
+    remaining: usize,
+    cut: bool,
+}
+
+impl fmt::Write for Truncator<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.cut {
+            return Ok(());
+        }
+
+        let mut end = s.len();
+
+        for (i, c) in s.char_indices() {
+            match self.segmenter.next(c) {
+                Segment::Start(width) if width > self.remaining => {
+                    self.cut = true;
+                    end = i;
+                    break;
+                }
+                Segment::Start(width) => self.remaining -= width,
+
+                // The start of the cluster has already been written, so without room to
+                // widen it, it is left as it is.
+                Segment::Widen if self.remaining == 0 => {
+                    self.cut = true;
+                    end = i;
+                    break;
+                }
+                Segment::Widen => self.remaining -= 1,
+
+                Segment::Escape | Segment::Continue => {}
+            }
+
+            self.open.push(c);
+        }
+
+        self.inner.write_str(&s[..end])
+    }
+}
+
+/// Tracks whether a style or hyperlink has been left open by the escape sequences
+/// written so far.
+#[derive(Default, Debug, Clone, Copy)]
+struct OpenSequences {
+    state: EscapeState,
+    style: bool,
+    hyperlink: bool,
+
+    /// Whether the current control sequence only has zero parameters so far.
+    reset: bool,
+
+    /// How much of the current operating system command matches a hyperlink.
+    osc: Osc,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+enum Osc {
+    #[default]
+    Start,
+    Eight,
+    Params,
+    Uri {
+        empty: bool,
+    },
+    Other,
+}
+
+impl OpenSequences {
+    fn push(&mut self, c: char) {
+        let prev = self.state;
+        self.state.advance(c);
+
+        match (prev, self.state) {
+            (EscapeState::Escape, EscapeState::Csi) => self.reset = true,
+            (EscapeState::Csi, EscapeState::Csi) => self.reset &= c == '0',
+
+            // Any SGR sequence other than a reset is assumed to change the style.
+            (EscapeState::Csi, EscapeState::Text) if c == 'm' => self.style = !self.reset,
+
+            (EscapeState::Escape, EscapeState::Osc) => self.osc = Osc::Start,
+            (EscapeState::Osc, EscapeState::Osc) => {
+                self.osc = match (self.osc, c) {
+                    (Osc::Start, '8') => Osc::Eight,
+                    (Osc::Eight, ';') => Osc::Params,
+                    (Osc::Params, ';') => Osc::Uri { empty: true },
+                    (Osc::Params, _) => Osc::Params,
+                    (Osc::Uri { .. }, _) => Osc::Uri { empty: false },
+                    _ => Osc::Other,
+                }
+            }
+
+            (EscapeState::Osc | EscapeState::OscEscape, EscapeState::Text) => {
+                if let Osc::Uri { empty } = self.osc {
+                    self.hyperlink = !empty;
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
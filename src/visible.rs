@@ -0,0 +1,119 @@
+//! Tracking of visible text, skipping over escape sequences.
+
+use core::fmt;
+
+/// Tracks whether text written to a stream is part of an escape sequence.
+///
+/// The state is kept between calls, so escape sequences may be split across several
+/// writes. Escape sequences only consist of ASCII characters, so the state can also be
+/// advanced one byte at a time, by passing each byte as a `char`. Malformed escape
+/// sequences end just before the first unexpected character, which is kept.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EscapeState {
+    #[default]
+    Text,
+
+    /// After an `ESC`.
+    Escape,
+
+    /// In an escape sequence with intermediate bytes (such as `ESC ( B`), which ends
+    /// with a byte in `0..=~`.
+    EscapeIntermediate,
+
+    /// In a control sequence (`ESC [`), which ends with a byte in `@..=~`.
+    Csi,
+
+    /// In an operating system command (`ESC ]`), which ends with `BEL` or `ESC \`.
+    Osc,
+
+    /// After an `ESC` in an operating system command.
+    OscEscape,
+}
+
+impl EscapeState {
+    /// Advance the state by one character, returning whether the character is visible.
+    #[inline]
+    pub(crate) fn advance(&mut self, c: char) -> bool {
+        let state = match (*self, c) {
+            (Self::Text, '\x1b') => Self::Escape,
+            (Self::Text, _) => return true,
+
+            (Self::Escape, '[') => Self::Csi,
+            (Self::Escape, ']') => Self::Osc,
+            (Self::Escape | Self::EscapeIntermediate, ' '..='/') => Self::EscapeIntermediate,
+            (Self::Escape | Self::EscapeIntermediate, '0'..='~') => Self::Text,
+
+            (Self::Csi, ' '..='?') => Self::Csi,
+            (Self::Csi, '@'..='~') => Self::Text,
+
+            (Self::Osc, '\x07') => Self::Text,
+            (Self::Osc, '\x1b') => Self::OscEscape,
+            (Self::Osc, _) => Self::Osc,
+
+            (Self::OscEscape, '\\') => Self::Text,
+
+            // An `ESC` that doesn't end the command starts a new escape sequence instead.
+            (Self::OscEscape, _) => {
+                *self = Self::Escape;
+                return self.advance(c);
+            }
+
+            // The sequence is malformed, so end it and treat the character as text.
+            (Self::Escape | Self::EscapeIntermediate | Self::Csi, _) => {
+                *self = Self::Text;
+                return self.advance(c);
+            }
+        };
+
+        *self = state;
+        false
+    }
+}
+
+/// Writes at most `remaining` visible characters to the inner writer. Escape sequences
+/// are always written, so that styles are still reset at the end.
+pub(crate) struct Truncate<W> {
+    inner: W,
+    state: EscapeState,
+    remaining: usize,
+}
+
+impl<W: fmt::Write> Truncate<W> {
+    #[inline]
+    pub(crate) fn new(inner: W, remaining: usize) -> Self {
+        Self {
+            inner,
+            state: EscapeState::Text,
+            remaining,
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for Truncate<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // The start of the text that hasn't been written or skipped yet.
+        let mut start = 0;
+
+        for (i, c) in s.char_indices() {
+            if !self.state.advance(c) {
+                continue;
+            }
+
+            if self.remaining > 0 {
+                self.remaining -= 1;
+                continue;
+            }
+
+            if start < i {
+                self.inner.write_str(&s[start..i])?;
+            }
+            start = i + c.len_utf8();
+        }
+
+        if start < s.len() {
+            self.inner.write_str(&s[start..])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+//! Measuring the display width of text.
+
+use core::fmt;
+
+use crate::visible::EscapeState;
+
+mod tables;
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{fe0f}';
+
+/// The number of columns a character takes up in a terminal.
+///
+/// East Asian wide and fullwidth characters and emoji take up two columns, while control
+/// characters, combining marks and other format characters take up none.
+pub(crate) fn char_width(c: char) -> usize {
+    if c.is_ascii() {
+        return match c {
+            ' '..='~' => 1,
+            _ => 0,
+        };
+    }
+
+    if c.is_control() || in_table(tables::ZERO_WIDTH, c) {
+        0
+    } else if in_table(tables::WIDE, c) {
+        2
+    } else {
+        1
+    }
+}
+
+fn in_table(table: &[(char, char)], c: char) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                core::cmp::Ordering::Less
+            } else if start > c {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// How a character written to a [`Segmenter`] affects the width of the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment {
+    /// Part of an escape sequence.
+    Escape,
+
+    /// The start of a new grapheme cluster, with the given width.
+    Start(usize),
+
+    /// Part of the current grapheme cluster, which doesn't change its width.
+    Continue,
+
+    /// Part of the current grapheme cluster, which makes it one column wider.
+    Widen,
+}
+
+/// Splits text into approximate grapheme clusters, skipping over escape sequences.
+///
+/// Zero width characters (such as combining marks and variation selectors), emoji skin
+/// tone modifiers and characters joined with a zero width joiner are part of the
+/// preceding cluster, and a pair of regional indicators makes up a single flag. The
+/// emoji presentation selector (VS16) makes a one column symbol two columns wide.
+#[derive(Default, Debug, Clone)]
+pub(crate) struct Segmenter {
+    state: EscapeState,
+    joined: bool,
+    regional: bool,
+
+    /// Whether there is a cluster for a skin tone modifier to be part of.
+    started: bool,
+
+    /// Whether the current cluster can be widened by the emoji presentation selector.
+    narrow_emoji: bool,
+}
+
+impl Segmenter {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: EscapeState::Text,
+            joined: false,
+            regional: false,
+            started: false,
+            narrow_emoji: false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self, c: char) -> Segment {
+        if !self.state.advance(c) {
+            return Segment::Escape;
+        }
+
+        let joined = core::mem::replace(&mut self.joined, c == ZERO_WIDTH_JOINER);
+        let regional = matches!(c, '\u{1f1e6}'..='\u{1f1ff}');
+        let paired = regional && self.regional;
+        self.regional = regional && !paired && !joined;
+
+        if joined || paired {
+            return Segment::Continue;
+        }
+
+        // A skin tone modifier changes how the emoji before it looks, and is only
+        // displayed on its own at the start of the text.
+        if matches!(c, '\u{1f3fb}'..='\u{1f3ff}') && self.started {
+            return Segment::Continue;
+        }
+
+        // Characters such as `❤` are displayed as two column emoji when followed by
+        // the emoji presentation selector.
+        if c == EMOJI_PRESENTATION_SELECTOR && self.narrow_emoji {
+            self.narrow_emoji = false;
+            return Segment::Widen;
+        }
+
+        // Flags are two columns wide, so an unpaired regional indicator is counted as
+        // two columns up front.
+        let width = if regional { 2 } else { char_width(c) };
+
+        match width {
+            0 => Segment::Continue,
+            width => {
+                self.started = true;
+
+                // Of the ASCII characters, only those used in keycap emoji have an
+                // emoji presentation.
+                self.narrow_emoji =
+                    width == 1 && (!c.is_ascii() || matches!(c, '#' | '*' | '0'..='9'));
+
+                Segment::Start(width)
+            }
+        }
+    }
+}
+
+/// A writer that measures the width of the text written to it, in terminal columns.
+///
+/// Escape sequences aren't counted, East Asian wide characters and emoji count as two
+/// columns, and combining marks count as none. A character joined to the previous one
+/// with a zero width joiner (as in many emoji sequences) is counted as part of it, as
+/// are skin tone modifiers and the second regional indicator of a flag. A narrow
+/// character followed by the emoji presentation selector (as in `"❤\u{fe0f}"`) counts
+/// as two columns.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Write;
+///
+/// use stylic::{Stylize, WidthCounter};
+///
+/// let mut counter = WidthCounter::new();
+/// write!(counter, "{} {}", "日本".bold(), "e\u{301}").unwrap();
+/// assert_eq!(counter.width(), 6);
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct WidthCounter {
+    segmenter: Segmenter,
+    width: usize,
+}
+
+impl WidthCounter {
+    /// Create a counter with a width of zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            segmenter: Segmenter::new(),
+            width: 0,
+        }
+    }
+
+    /// The width of the text written so far.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl fmt::Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.segmenter.next(c) {
+                Segment::Start(width) => self.width += width,
+                Segment::Widen => self.width += 1,
+                Segment::Escape | Segment::Continue => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Measure the width of a value when displayed.
+pub(crate) fn display_width(value: &impl fmt::Display) -> usize {
+    let mut counter = WidthCounter::new();
+    let _ = fmt::write(&mut counter, format_args!("{value}"));
+    counter.width()
+}
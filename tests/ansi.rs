@@ -0,0 +1,196 @@
+//! Tests of parsing escape sequences back into styles and hyperlinks.
+
+mod common;
+
+use common::{render_style, ATTRIBUTES};
+use stylic::{
+    parse_ansi, with_style_mode, AnsiColor, AnsiEvent, Attributes, Color, Hyperlink, Style,
+    StyleMode,
+};
+
+fn events(s: &str) -> Vec<AnsiEvent<'_>> {
+    parse_ansi(s).collect()
+}
+
+fn text(style: Style, s: &str) -> AnsiEvent<'_> {
+    AnsiEvent::Text(style, s)
+}
+
+#[test]
+fn plain_text() {
+    assert_eq!(events(""), []);
+    assert_eq!(events("hello\nworld"), [text(Style::new(), "hello\nworld")]);
+}
+
+#[test]
+fn sgr() {
+    assert_eq!(
+        events("a\x1b[1;31mb\x1b[22mc\x1b[mdx"),
+        [
+            text(Style::new(), "a"),
+            text(Style::new().bold().red(), "b"),
+            text(Style::new().red(), "c"),
+            text(Style::new(), "dx"),
+        ]
+    );
+
+    // Parameters accumulate until they are reset.
+    assert_eq!(
+        events("\x1b[1m\x1b[3m\x1b[44mx\x1b[;4my"),
+        [
+            text(Style::new().bold().italic().on_blue(), "x"),
+            text(Style::new().underlined(), "y"),
+        ]
+    );
+}
+
+#[test]
+fn colors() {
+    let fg = |s: &str| match events(s).as_slice() {
+        [AnsiEvent::Text(style, "x")] => style.fg,
+        events => panic!("unexpected events {events:?}"),
+    };
+
+    assert_eq!(fg("\x1b[30mx"), Color::Ansi(AnsiColor::Black));
+    assert_eq!(fg("\x1b[97mx"), Color::Ansi(AnsiColor::BrightWhite));
+    assert_eq!(fg("\x1b[38;5;208mx"), Color::Ansi256(208));
+    assert_eq!(fg("\x1b[38:5:208mx"), Color::Ansi256(208));
+    assert_eq!(fg("\x1b[38;2;1;2;3mx"), Color::Rgb(1, 2, 3));
+    assert_eq!(fg("\x1b[38:2:1:2:3mx"), Color::Rgb(1, 2, 3));
+    assert_eq!(fg("\x1b[38:2::1:2:3mx"), Color::Rgb(1, 2, 3));
+    assert_eq!(fg("\x1b[31;39mx"), Color::Default);
+
+    assert_eq!(
+        events("\x1b[104;58;2;9;8;7mx"),
+        [text(
+            Style::new()
+                .on_bright_blue()
+                .underline_color(Color::Rgb(9, 8, 7)),
+            "x"
+        )]
+    );
+}
+
+#[test]
+fn underline_styles() {
+    assert_eq!(
+        events("\x1b[4:3ma\x1b[4:0mb\x1b[4:2mc"),
+        [
+            text(Style::new().curly_underlined(), "a"),
+            text(Style::new(), "b"),
+            text(Style::new().double_underlined(), "c"),
+        ]
+    );
+}
+
+#[test]
+fn rendered_styles_round_trip() {
+    let round_trip = |style: Style| {
+        let rendered = render_style(style);
+
+        assert_eq!(
+            events(&rendered),
+            [text(style, "x")],
+            "failed to parse {rendered:?}"
+        );
+    };
+
+    for bits in 0..1u16 << ATTRIBUTES.len() {
+        let attributes = ATTRIBUTES
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| bits & (1 << i) != 0)
+            .fold(Attributes::EMPTY, |all, (_, (attribute, _))| {
+                all | attribute
+            });
+
+        round_trip(Style::from(attributes));
+    }
+
+    for i in 0..=255 {
+        round_trip(Style::new().ansi256_color(i).on_color_ansi(AnsiColor::Cyan));
+    }
+
+    round_trip(
+        Style::new()
+            .rgb_color(255, 0, 128)
+            .on_color_rgb(0, 1, 2)
+            .dashed_underlined()
+            .underline_color(Color::Rgb(3, 4, 5)),
+    );
+}
+
+#[test]
+fn hyperlinks() {
+    let rendered = with_style_mode(StyleMode::enable(), || {
+        Hyperlink::new("https://example.com", "link")
+            .bold()
+            .to_string()
+    });
+
+    assert_eq!(
+        events(&rendered),
+        [
+            AnsiEvent::HyperlinkStart("https://example.com"),
+            text(Style::new().bold(), "link"),
+            AnsiEvent::HyperlinkEnd,
+        ]
+    );
+
+    assert_eq!(
+        events("\x1b]8;id=1;https://example.com\x07a\x1b]8;;\x07b"),
+        [
+            AnsiEvent::HyperlinkStart("https://example.com"),
+            text(Style::new(), "a"),
+            AnsiEvent::HyperlinkEnd,
+            text(Style::new(), "b"),
+        ]
+    );
+}
+
+#[test]
+fn other_sequences_are_skipped() {
+    assert_eq!(
+        events("a\x1b[2Kb\x1b[?25lc\x1b]0;title\x07d\x1b(Be\x1b7f"),
+        [
+            text(Style::new(), "a"),
+            text(Style::new(), "b"),
+            text(Style::new(), "c"),
+            text(Style::new(), "d"),
+            text(Style::new(), "e"),
+            text(Style::new(), "f"),
+        ]
+    );
+}
+
+#[test]
+fn malformed_input() {
+    // Unknown and invalid parameters are ignored.
+    assert_eq!(
+        events("\x1b[1;999;38;5;300;38;2;1mx"),
+        [text(Style::new().bold(), "x")]
+    );
+
+    // Unexpected characters end a sequence, and are kept.
+    assert_eq!(
+        events("\x1b[31é\x1b[32\x1b[1mx"),
+        [text(Style::new(), "é"), text(Style::new().bold(), "x")]
+    );
+
+    // Unterminated sequences are dropped.
+    assert_eq!(events("a\x1b[31"), [text(Style::new(), "a")]);
+    assert_eq!(
+        events("a\x1b]8;;https://example.com"),
+        [text(Style::new(), "a")]
+    );
+    assert_eq!(events("a\x1b"), [text(Style::new(), "a")]);
+
+    // An interrupted hyperlink is ignored.
+    assert_eq!(
+        events("\x1b]8;;https://example.com\x1b[1mx"),
+        [text(Style::new().bold(), "x")]
+    );
+
+    // Ending a hyperlink that isn't open does nothing.
+    assert_eq!(events("\x1b]8;;\x1b\\x"), [text(Style::new(), "x")]);
+}
@@ -0,0 +1,26 @@
+use stylic::Attributes;
+
+#[test]
+fn bits() {
+    let original = Attributes::BOLD | Attributes::ITALIC | Attributes::CROSSED;
+    assert_eq!(u8::try_from(original), Ok(0b1000_0101));
+    assert_eq!(u16::from(original), 0b1000_0101);
+
+    let extended = Attributes::BOLD | Attributes::SUBSCRIPT;
+    assert!(u8::try_from(extended).is_err());
+    assert_eq!(u16::from(extended), 1 << 14 | 1);
+
+    assert_eq!(u8::try_from(Attributes::EMPTY), Ok(0));
+}
+
+#[test]
+fn not() {
+    assert_eq!(u16::from(!Attributes::EMPTY), (1 << 15) - 1);
+    assert_eq!(!!Attributes::EMPTY, Attributes::EMPTY);
+    assert_eq!(
+        Attributes::EMPTY.not().and(Attributes::BOLD.not()),
+        !Attributes::BOLD
+    );
+    assert!((!Attributes::BOLD).contains(Attributes::SUBSCRIPT));
+    assert!(!(!Attributes::BOLD).contains(Attributes::BOLD));
+}
@@ -0,0 +1,73 @@
+//! Fixtures and helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::fmt;
+
+use stylic::{with_style_mode, AnsiColor, Attributes, ColorLevel, Style, StyleMode, Styled};
+
+pub const LEVELS: [ColorLevel; 5] = [
+    ColorLevel::None,
+    ColorLevel::Attributes,
+    ColorLevel::Ansi,
+    ColorLevel::Ansi256,
+    ColorLevel::Rgb,
+];
+
+pub const ANSI_COLORS: [AnsiColor; 16] = [
+    AnsiColor::Black,
+    AnsiColor::Red,
+    AnsiColor::Green,
+    AnsiColor::Yellow,
+    AnsiColor::Blue,
+    AnsiColor::Magenta,
+    AnsiColor::Cyan,
+    AnsiColor::White,
+    AnsiColor::BrightBlack,
+    AnsiColor::BrightRed,
+    AnsiColor::BrightGreen,
+    AnsiColor::BrightYellow,
+    AnsiColor::BrightBlue,
+    AnsiColor::BrightMagenta,
+    AnsiColor::BrightCyan,
+    AnsiColor::BrightWhite,
+];
+
+// Each attribute with its SGR parameter, in bit order.
+pub const ATTRIBUTES: [(Attributes, &str); 15] = [
+    (Attributes::BOLD, "1"),
+    (Attributes::DIM, "2"),
+    (Attributes::ITALIC, "3"),
+    (Attributes::UNDERLINED, "4"),
+    (Attributes::BLINKING, "5"),
+    (Attributes::INVERTED, "7"),
+    (Attributes::HIDDEN, "8"),
+    (Attributes::CROSSED, "9"),
+    (Attributes::RAPID_BLINKING, "6"),
+    (Attributes::DOUBLE_UNDERLINED, "21"),
+    (Attributes::FRAMED, "51"),
+    (Attributes::ENCIRCLED, "52"),
+    (Attributes::OVERLINED, "53"),
+    (Attributes::SUPERSCRIPT, "73"),
+    (Attributes::SUBSCRIPT, "74"),
+];
+
+/// Run `f` with styling enabled for the current thread.
+pub fn enabled<T>(f: impl FnOnce() -> T) -> T {
+    with_style_mode(StyleMode::enable(), f)
+}
+
+/// Render a value with styling enabled.
+pub fn render(value: impl fmt::Display) -> String {
+    enabled(|| value.to_string())
+}
+
+/// Render `x` in the given style.
+pub fn render_style(style: Style) -> String {
+    render(Styled::new("x").style(style))
+}
+
+/// `x` rendered with the given SGR parameters, after the leading reset.
+pub fn expected(params: &str) -> String {
+    format!("\x1b[0{params}mx\x1b[0m")
+}
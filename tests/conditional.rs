@@ -0,0 +1,39 @@
+//! Tests of styles that are only applied under some condition.
+
+mod common;
+
+use common::{enabled, expected, render_style};
+use stylic::{Hyperlink, Style, Styled};
+
+#[test]
+fn conditional_styles() {
+    const ERROR: Style = Style::new().red();
+
+    enabled(|| {
+        let styled = |failed: bool| Styled::new("x").bold().style_if(failed, ERROR).to_string();
+        assert_eq!(styled(true), expected(";31;1"));
+        assert_eq!(styled(false), expected(";1"));
+
+        let styled = |style: Option<Style>| Styled::new("x").bold().maybe_style(style).to_string();
+        assert_eq!(styled(Some(ERROR)), expected(";31;1"));
+        assert_eq!(styled(None), expected(";1"));
+
+        assert_eq!(render_style(ERROR.when(true)), expected(";31"));
+        assert_eq!(render_style(ERROR.when(false)), expected(""));
+
+        assert_eq!(render_style(Style::from(Some(ERROR))), expected(";31"));
+        assert_eq!(render_style(Style::from(None)), expected(""));
+
+        assert_eq!(
+            Hyperlink::new("https://example.com", "x")
+                .blue()
+                .style_if(true, ERROR)
+                .maybe_style(None)
+                .to_string(),
+            format!(
+                "\x1b]8;;https://example.com\x1b\\{}\x1b]8;;\x1b\\",
+                expected(";31")
+            )
+        );
+    });
+}
@@ -0,0 +1,112 @@
+use stylic::{
+    apply, apply_hyperlink, hyperlink, styled, with_style_mode, Hyperlink, Stream, Style,
+    StyleMode, Styled,
+};
+
+fn disabled<T>(f: impl FnOnce() -> T) -> T {
+    with_style_mode(StyleMode::disable(), f)
+}
+
+#[test]
+fn styled() {
+    disabled(|| {
+        assert_eq!(Styled::new("Hello").bold().red().to_string(), "Hello");
+        assert_eq!(Styled::new(42).on_blue().to_string(), "42");
+    });
+}
+
+#[test]
+fn styled_macro() {
+    disabled(|| {
+        assert_eq!(styled!("Hello").red().to_string(), "Hello");
+        assert_eq!(
+            styled!("{}, {}!", "Hello", 42).italic().to_string(),
+            "Hello, 42!"
+        );
+    });
+}
+
+#[test]
+fn apply_macro() {
+    const STYLE: Style = Style::new().bold().blue();
+
+    disabled(|| {
+        assert_eq!(apply!(STYLE => "Hello").to_string(), "Hello");
+        assert_eq!(apply!(STYLE => "{}!", "Hello").to_string(), "Hello!");
+    });
+}
+
+#[test]
+fn style() {
+    disabled(|| {
+        assert_eq!(Style::new().bold().red().to_string(), "");
+    });
+}
+
+#[test]
+fn nested() {
+    disabled(|| {
+        let value = styled!(
+            "the quick {} fox jumps over the lazy dog",
+            styled!("brown").rgb_color(161, 123, 90)
+        )
+        .cyan();
+
+        assert_eq!(
+            value.to_string(),
+            "the quick brown fox jumps over the lazy dog"
+        );
+
+        let value = styled!("a{}c", styled!("b{}", styled!("!").bold()).red()).green();
+        assert_eq!(value.to_string(), "ab!c");
+    });
+}
+
+#[test]
+fn hyperlink() {
+    disabled(|| {
+        assert_eq!(
+            Hyperlink::new("https://rust-lang.org", "Rust")
+                .bold()
+                .to_string(),
+            "Rust"
+        );
+        assert_eq!(
+            hyperlink!("https://rust-lang.org"; "Rust {}", 2021)
+                .green()
+                .to_string(),
+            "Rust 2021"
+        );
+        assert_eq!(
+            apply_hyperlink!(Style::new().italic() => "https://rust-lang.org"; "Rust").to_string(),
+            "Rust"
+        );
+    });
+}
+
+#[test]
+fn hyperlink_nested_in_styled() {
+    disabled(|| {
+        let value = styled!("see {}", hyperlink!("https://rust-lang.org"; "Rust").bold()).red();
+        assert_eq!(value.to_string(), "see Rust");
+    });
+}
+
+#[test]
+fn for_stream() {
+    disabled(|| {
+        let value = styled!("Hello").red().for_stream(Stream::Stderr);
+        assert_eq!(value.to_string(), "Hello");
+    });
+}
+
+#[test]
+fn inherit() {
+    disabled(|| {
+        let value = styled!("a{}c", styled!("b").bold().inherit()).red();
+        assert_eq!(value.to_string(), "abc");
+
+        let value = hyperlink!("https://rust-lang.org"; "Rust").bold().inherit();
+        assert_eq!(value.to_string(), "Rust");
+    });
+}
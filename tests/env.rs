@@ -0,0 +1,123 @@
+use stylic::{ColorLevel, Stream, StyleMode};
+
+const VARS: [&str; 8] = [
+    "FORCE_COLOR",
+    "CLICOLOR_FORCE",
+    "CLICOLOR",
+    "NO_COLOR",
+    "TERM",
+    "COLORTERM",
+    "TERM_PROGRAM",
+    "WT_SESSION",
+];
+
+/// Detect the color level with only the given variables set.
+fn detect(vars: &[(&str, &str)], is_terminal: bool) -> ColorLevel {
+    let vars: Vec<_> = VARS
+        .iter()
+        .map(|&name| {
+            let value = vars.iter().find(|(k, _)| *k == name).map(|(_, v)| *v);
+            (name, value)
+        })
+        .collect();
+
+    temp_env::with_vars(vars, || ColorLevel::detect(is_terminal))
+}
+
+const TERM: (&str, &str) = ("TERM", "xterm");
+
+#[test]
+fn terminal() {
+    assert_eq!(detect(&[TERM], true), ColorLevel::Ansi);
+    assert_eq!(
+        detect(&[("TERM", "xterm-256color")], true),
+        ColorLevel::Ansi256
+    );
+    assert_eq!(detect(&[("TERM", "xterm-direct")], true), ColorLevel::Rgb);
+    assert_eq!(
+        detect(&[TERM, ("COLORTERM", "truecolor")], true),
+        ColorLevel::Rgb
+    );
+
+    assert_eq!(detect(&[], true), ColorLevel::None);
+    assert_eq!(detect(&[("TERM", "dumb")], true), ColorLevel::None);
+    assert_eq!(detect(&[TERM], false), ColorLevel::None);
+}
+
+#[test]
+fn no_color() {
+    assert_eq!(detect(&[TERM, ("NO_COLOR", "1")], true), ColorLevel::None);
+
+    // Any non-empty value disables styling.
+    assert_eq!(detect(&[TERM, ("NO_COLOR", "0")], true), ColorLevel::None);
+    assert_eq!(detect(&[TERM, ("NO_COLOR", "")], true), ColorLevel::Ansi);
+}
+
+#[test]
+fn clicolor() {
+    assert_eq!(detect(&[TERM, ("CLICOLOR", "0")], true), ColorLevel::None);
+    assert_eq!(detect(&[TERM, ("CLICOLOR", "1")], true), ColorLevel::Ansi);
+
+    // `CLICOLOR` doesn't enable styling for outputs that aren't terminals.
+    assert_eq!(detect(&[TERM, ("CLICOLOR", "1")], false), ColorLevel::None);
+}
+
+#[test]
+fn forced() {
+    for force in [("FORCE_COLOR", "1"), ("CLICOLOR_FORCE", "1")] {
+        assert_eq!(detect(&[force], false), ColorLevel::Ansi);
+        assert_eq!(
+            detect(&[force, ("TERM", "xterm-256color")], false),
+            ColorLevel::Ansi256
+        );
+
+        // Forcing takes precedence over everything else.
+        assert_eq!(
+            detect(&[force, TERM, ("CLICOLOR", "0"), ("NO_COLOR", "1")], false),
+            ColorLevel::Ansi
+        );
+    }
+
+    assert_eq!(detect(&[("FORCE_COLOR", "2")], false), ColorLevel::Ansi256);
+    assert_eq!(detect(&[("FORCE_COLOR", "3")], false), ColorLevel::Rgb);
+    assert_eq!(detect(&[("CLICOLOR_FORCE", "0")], false), ColorLevel::None);
+}
+
+#[test]
+fn force_color_precedence() {
+    // `FORCE_COLOR` is checked before `CLICOLOR_FORCE`.
+    for value in ["0", "false"] {
+        assert_eq!(
+            detect(
+                &[("FORCE_COLOR", value), ("CLICOLOR_FORCE", "1"), TERM],
+                true
+            ),
+            ColorLevel::None
+        );
+    }
+
+    // An empty `FORCE_COLOR` is the same as it being unset.
+    assert_eq!(detect(&[("FORCE_COLOR", "")], false), ColorLevel::None);
+    assert_eq!(
+        detect(&[("FORCE_COLOR", ""), ("CLICOLOR_FORCE", "1")], false),
+        ColorLevel::Ansi
+    );
+    assert_eq!(
+        detect(&[("FORCE_COLOR", ""), TERM, ("NO_COLOR", "1")], true),
+        ColorLevel::None
+    );
+}
+
+#[test]
+fn custom_stream() {
+    // Custom streams are never terminals, so only forcing enables styling.
+    temp_env::with_vars(VARS.map(|name| (name, None::<&str>)), || {
+        Stream::Custom.set_style_mode(StyleMode::Auto);
+        assert_eq!(Stream::Custom.color_level(), ColorLevel::None);
+    });
+
+    temp_env::with_vars([("FORCE_COLOR", Some("3"))], || {
+        Stream::Custom.set_style_mode(StyleMode::Auto);
+        assert_eq!(Stream::Custom.color_level(), ColorLevel::Rgb);
+    });
+}
@@ -0,0 +1,203 @@
+//! Tests of formatting traits and options, which apply to the visible content only.
+
+mod common;
+
+use common::enabled;
+use stylic::{hyperlink, styled, with_style_mode, Hyperlink, Stream, StyleMode, Styled};
+
+#[test]
+fn styled_options() {
+    enabled(|| {
+        assert_eq!(
+            format!("{:05}", Styled::new(42).red()),
+            "\x1b[0;31m00042\x1b[0m"
+        );
+        assert_eq!(format!("{:+}", Styled::new(5).red()), "\x1b[0;31m+5\x1b[0m");
+        assert_eq!(
+            format!("{:>6}", Styled::new("ok").red()),
+            "\x1b[0;31m    ok\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:.2}", Styled::new(1.0 / 3.0).red()),
+            "\x1b[0;31m0.33\x1b[0m"
+        );
+    });
+}
+
+#[test]
+fn format_args_width() {
+    enabled(|| {
+        assert_eq!(
+            format!("{:>6}", styled!("ok").green()),
+            "\x1b[0;32m    ok\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:6}", styled!("o{}", "k").green()),
+            "\x1b[0;32mok    \x1b[0m"
+        );
+        assert_eq!(
+            format!("{:-^7}", styled!("ok").green()),
+            "\x1b[0;32m--ok---\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:1}", styled!("long").green()),
+            "\x1b[0;32mlong\x1b[0m"
+        );
+    });
+}
+
+#[test]
+fn format_args_precision() {
+    enabled(|| {
+        assert_eq!(
+            format!("{:.3}", styled!("hello").green()),
+            "\x1b[0;32mhel\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:>5.3}", styled!("hello").green()),
+            "\x1b[0;32m  hel\x1b[0m"
+        );
+    });
+}
+
+#[test]
+fn escapes_are_not_counted() {
+    enabled(|| {
+        let inner = "\x1b[31mok\x1b[0m";
+
+        assert_eq!(
+            format!("{:>6}", styled!("{inner}")),
+            format!("\x1b[0m    {inner}\x1b[0m")
+        );
+        assert_eq!(
+            format!("{:.1}", styled!("{inner}")),
+            "\x1b[0m\x1b[31mo\x1b[0m\x1b[0m"
+        );
+        assert_eq!(
+            format!(
+                "{:<4}",
+                styled!("\x1b]8;;https://example.com\x07ok\x1b]8;;\x1b\\")
+            ),
+            "\x1b[0m\x1b]8;;https://example.com\x07ok\x1b]8;;\x1b\\  \x1b[0m"
+        );
+    });
+}
+
+#[test]
+fn hyperlink_options() {
+    enabled(|| {
+        assert_eq!(
+            format!("{:>4}", Hyperlink::new("https://example.com", "ok")),
+            "\x1b]8;;https://example.com\x1b\\\x1b[0m  ok\x1b[0m\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            format!("{:>4}", hyperlink!("https://example.com"; "ok")),
+            "\x1b]8;;https://example.com\x1b\\\x1b[0m  ok\x1b[0m\x1b]8;;\x1b\\"
+        );
+    });
+}
+
+#[test]
+fn disabled_options() {
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(format!("{:>6}", styled!("ok").green()), "    ok");
+        assert_eq!(format!("{:.1}", styled!("ok").green()), "o");
+        assert_eq!(
+            format!("{:>4}", Hyperlink::new("https://example.com", "ok")),
+            "  ok"
+        );
+    });
+}
+
+#[test]
+fn fmt_traits() {
+    enabled(|| {
+        let styled = |s: String| format!("\x1b[0;31m{s}\x1b[0m");
+        let n = Styled::new(255).red();
+
+        assert_eq!(format!("{n:x}"), styled(format!("{:x}", 255)));
+        assert_eq!(format!("{n:#X}"), styled(format!("{:#X}", 255)));
+        assert_eq!(format!("{n:o}"), styled(format!("{:o}", 255)));
+        assert_eq!(format!("{n:#010b}"), styled(format!("{:#010b}", 255)));
+
+        let f = Styled::new(1234.5).red();
+        assert_eq!(format!("{f:e}"), styled(format!("{:e}", 1234.5)));
+        assert_eq!(format!("{f:.2E}"), styled(format!("{:.2E}", 1234.5)));
+
+        let value = 0;
+        let p = Styled::new(&value).red();
+        assert_eq!(format!("{p:p}"), styled(format!("{:p}", &value)));
+
+        assert_eq!(
+            format!("{:x}", n.for_stream(Stream::Stdout)),
+            styled("ff".to_owned())
+        );
+    });
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(format!("{:x}", Styled::new(255).red()), "ff");
+    });
+}
+
+#[test]
+fn debug_adapter() {
+    enabled(|| {
+        assert_eq!(
+            Styled::new(Some("a")).red().debug().to_string(),
+            "\x1b[0;31mSome(\"a\")\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:#}", Styled::new(["a"]).red().debug()),
+            "\x1b[0;31m[\n    \"a\",\n]\x1b[0m"
+        );
+    });
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(Styled::new("a").red().debug().to_string(), "\"a\"");
+    });
+}
+
+#[test]
+fn wide_characters() {
+    enabled(|| {
+        assert_eq!(
+            format!("[{:>6}]", Styled::new("日本").red()),
+            "[\x1b[0;31m  日本\x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:^7}]", styled!("{}", "日本").red()),
+            "[\x1b[0;31m 日本  \x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:6.1}]", styled!("日本").red()),
+            "[\x1b[0;31m日    \x1b[0m]"
+        );
+
+        // Without an alignment, the content is aligned the way its type aligns itself.
+        assert_eq!(
+            format!("[{:6}]", Styled::new("日本").red()),
+            "[\x1b[0;31m日本  \x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:6}]", styled!("{}", "日本").red()),
+            "[\x1b[0;31m日本  \x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:6}]", Styled::new(-1.5).red()),
+            "[\x1b[0;31m  -1.5\x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:6}]", Styled::new(" a ").red()),
+            "[\x1b[0;31m a    \x1b[0m]"
+        );
+    });
+}
+
+#[test]
+fn format_args_flags() {
+    enabled(|| {
+        // Like for a string, flags don't apply to formatted content.
+        assert_eq!(format!("{:+}", styled!("{}", 5)), "\x1b[0m5\x1b[0m");
+        assert_eq!(format!("{}", styled!("{:+}", 5)), "\x1b[0m+5\x1b[0m");
+    });
+}
@@ -0,0 +1,290 @@
+//! Byte-exact tests of the escape sequences that are written.
+
+mod common;
+
+use common::{enabled, expected, render, render_style, ANSI_COLORS, ATTRIBUTES};
+use stylic::{
+    hyperlink, styled, with_style_mode, Attributes, Color, ColorLevel, Hyperlink, Style, StyleMode,
+    Styled,
+};
+
+#[test]
+fn default_style() {
+    assert_eq!(render_style(Style::new()), "\x1b[0mx\x1b[0m");
+}
+
+#[test]
+fn ansi_colors() {
+    for (i, color) in ANSI_COLORS.into_iter().enumerate() {
+        let (fg, bg) = match i {
+            0..=7 => (30 + i, 40 + i),
+            _ => (90 + i - 8, 100 + i - 8),
+        };
+
+        assert_eq!(
+            render_style(Style::new().ansi_color(color)),
+            expected(&format!(";{fg}"))
+        );
+        assert_eq!(
+            render_style(Style::new().on_color_ansi(color)),
+            expected(&format!(";{bg}"))
+        );
+    }
+}
+
+#[test]
+fn ansi256_colors() {
+    for i in 0..=255 {
+        assert_eq!(
+            render_style(Style::new().ansi256_color(i)),
+            expected(&format!(";38;5;{i}"))
+        );
+        assert_eq!(
+            render_style(Style::new().on_color_ansi256(i)),
+            expected(&format!(";48;5;{i}"))
+        );
+    }
+}
+
+#[test]
+fn rgb_colors() {
+    for (r, g, b) in [(0, 0, 0), (255, 255, 255), (1, 22, 133), (161, 123, 90)] {
+        assert_eq!(
+            render_style(Style::new().rgb_color(r, g, b)),
+            expected(&format!(";38;2;{r};{g};{b}"))
+        );
+        assert_eq!(
+            render_style(Style::new().on_color_rgb(r, g, b)),
+            expected(&format!(";48;2;{r};{g};{b}"))
+        );
+    }
+}
+
+#[test]
+fn every_attribute_combination() {
+    for bits in 0..1u16 << ATTRIBUTES.len() {
+        let mut attributes = Attributes::EMPTY;
+        let mut params = String::new();
+
+        // Parameters are written from the highest bit to the lowest.
+        for (i, (attribute, param)) in ATTRIBUTES.into_iter().enumerate().rev() {
+            if bits & (1 << i) != 0 {
+                attributes |= attribute;
+                params.push(';');
+                params.push_str(param);
+            }
+        }
+
+        assert_eq!(render_style(Style::from(attributes)), expected(&params));
+    }
+}
+
+#[test]
+fn colors_and_attributes() {
+    assert_eq!(
+        render_style(Style::new().red().on_blue().bold().italic()),
+        expected(";31;44;3;1")
+    );
+}
+
+#[test]
+fn style_display() {
+    enabled(|| {
+        assert_eq!(Style::new().red().bold().to_string(), "\x1b[0;31;1m");
+        assert_eq!(Style::new().to_string(), "\x1b[0m");
+    });
+}
+
+#[test]
+fn quantized_colors() {
+    let render_at = |level, style: Style| {
+        with_style_mode(StyleMode::Force(level), || {
+            Styled::new("x").style(style).to_string()
+        })
+    };
+
+    let style = Style::new()
+        .rgb_color(255, 0, 0)
+        .on_color_ansi256(21)
+        .bold();
+
+    assert_eq!(
+        render_at(ColorLevel::Rgb, style),
+        expected(";38;2;255;0;0;48;5;21;1")
+    );
+    assert_eq!(
+        render_at(ColorLevel::Ansi256, style),
+        expected(";38;5;196;48;5;21;1")
+    );
+    assert_eq!(render_at(ColorLevel::Ansi, style), expected(";91;44;1"));
+    assert_eq!(render_at(ColorLevel::Attributes, style), expected(";1"));
+    assert_eq!(render_at(ColorLevel::None, style), "x");
+}
+
+#[test]
+fn hyperlinks() {
+    enabled(|| {
+        assert_eq!(
+            Hyperlink::new("https://rust-lang.org", "Rust").to_string(),
+            "\x1b]8;;https://rust-lang.org\x1b\\\x1b[0mRust\x1b[0m\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            hyperlink!("https://rust-lang.org"; "Rust {}", 2021)
+                .bold()
+                .green()
+                .to_string(),
+            "\x1b]8;;https://rust-lang.org\x1b\\\x1b[0;32;1mRust 2021\x1b[0m\x1b]8;;\x1b\\"
+        );
+    });
+}
+
+#[test]
+fn transitions() {
+    enabled(|| {
+        let transition = |from: Style, to: Style| from.transition_to(to).to_string();
+
+        assert_eq!(transition(Style::new().red(), Style::new().red()), "");
+        assert_eq!(transition(Style::new().red(), Style::new()), "\x1b[0m");
+        assert_eq!(transition(Style::new(), Style::new().red()), "\x1b[31m");
+        assert_eq!(
+            transition(Style::new().red().on_blue(), Style::new().bold()),
+            "\x1b[1;39;49m"
+        );
+        assert_eq!(
+            transition(Style::new().bold().dim(), Style::new().dim()),
+            "\x1b[22;2m"
+        );
+        assert_eq!(
+            transition(
+                Style::new()
+                    .italic()
+                    .underlined()
+                    .blinking()
+                    .inverted()
+                    .red(),
+                Style::new().hidden().crossed().red()
+            ),
+            "\x1b[23;24;25;27;9;8m"
+        );
+        assert_eq!(
+            transition(
+                Style::new()
+                    .rapid_blinking()
+                    .framed()
+                    .overlined()
+                    .subscript(),
+                Style::new().encircled().superscript()
+            ),
+            "\x1b[25;54;55;75;73;52m"
+        );
+        // Turning off every attribute while setting every color.
+        let all = ATTRIBUTES
+            .into_iter()
+            .fold(Attributes::EMPTY, |all, (attribute, _)| all | attribute);
+        assert_eq!(
+            transition(
+                Style::from(all),
+                Style::new()
+                    .bold()
+                    .rgb_color(255, 255, 255)
+                    .on_color_rgb(255, 255, 255)
+                    .underline_color(Color::Rgb(255, 255, 255))
+            ),
+            "\x1b[22;23;24;25;27;28;29;54;55;75;1;38;2;255;255;255;48;2;255;255;255;58;2;255;255;255m"
+        );
+        assert_eq!(
+            transition(Style::new().underlined(), Style::new().curly_underlined()),
+            "\x1b[4:3m"
+        );
+        assert_eq!(
+            transition(Style::new().dotted_underlined(), Style::new().underlined()),
+            "\x1b[4m"
+        );
+        assert_eq!(
+            transition(Style::new().curly_underlined(), Style::new().bold()),
+            "\x1b[24;1m"
+        );
+        assert_eq!(
+            transition(
+                Style::new().underlined().underline_color(Color::Ansi256(1)),
+                Style::new().underlined()
+            ),
+            "\x1b[59m"
+        );
+    });
+}
+
+#[cfg(not(feature = "nested_styles"))]
+#[test]
+fn nested() {
+    let value = styled!("a{}c", styled!("b").red()).bold();
+    assert_eq!(render(value), "\x1b[0;1ma\x1b[0;31mb\x1b[0mc\x1b[0m");
+}
+
+#[cfg(feature = "nested_styles")]
+#[test]
+fn nested() {
+    let value = styled!("a{}c", styled!("b").red()).bold();
+    assert_eq!(render(value), "\x1b[0;1ma\x1b[0;31mb\x1b[1;39mc\x1b[0m");
+
+    let value = styled!("a{}c", styled!("b").red().inherit())
+        .bold()
+        .on_blue();
+    assert_eq!(render(value), "\x1b[0;44;1ma\x1b[31mb\x1b[39mc\x1b[0m");
+
+    let value = styled!("a{}d", styled!("b{}", styled!("c").italic()).red()).bold();
+    assert_eq!(
+        render(value),
+        "\x1b[0;1ma\x1b[0;31mb\x1b[0;3mc\x1b[23;31m\x1b[1;39md\x1b[0m"
+    );
+
+    // The end of an inheriting value resets and then reapplies its own style on top of
+    // the enclosing style, which the enclosing value turns into a transition.
+    let value = styled!(
+        "a{}d",
+        styled!("b{}", styled!("c").italic().inherit())
+            .bold()
+            .inherit()
+    )
+    .red();
+    assert_eq!(
+        render(value),
+        "\x1b[0;31ma\x1b[1mb\x1b[3mc\x1b[22;23m\x1b[1m\x1b[22md\x1b[0m"
+    );
+
+    // A nested value without a style keeps the enclosing style.
+    let value = styled!("a{}c", Styled::new("b")).red();
+    assert_eq!(render(value), "\x1b[0;31mabc\x1b[0m");
+
+    // The end of a nested value only changes what differs from the enclosing style.
+    let value = styled!("a {} b", styled!("x").bold()).red();
+    assert_eq!(render(value), "\x1b[0;31ma \x1b[0;1mx\x1b[22;31m b\x1b[0m");
+}
+
+#[cfg(feature = "nested_styles")]
+#[test]
+fn nested_hyperlink() {
+    let value = styled!("see {}", hyperlink!("https://rust-lang.org"; "Rust").bold()).red();
+    assert_eq!(
+        render(value),
+        "\x1b[0;31msee \x1b]8;;https://rust-lang.org\x1b\\\x1b[0;1mRust\x1b[22;31m\x1b]8;;\x1b\\\x1b[0m"
+    );
+
+    let value = styled!(
+        "see {}",
+        hyperlink!("https://rust-lang.org"; "Rust").bold().inherit()
+    )
+    .red();
+    assert_eq!(
+        render(value),
+        "\x1b[0;31msee \x1b]8;;https://rust-lang.org\x1b\\\x1b[1mRust\x1b[22m\x1b]8;;\x1b\\\x1b[0m"
+    );
+}
+
+#[test]
+fn color_default_is_not_written() {
+    assert_eq!(
+        render_style(Style::new().fg(Color::Default).bold()),
+        expected(";1")
+    );
+}
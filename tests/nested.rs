@@ -0,0 +1,74 @@
+//! Tests of nested styles with the `nested_styles` feature.
+
+mod common;
+
+use common::{enabled, render};
+use stylic::{styled, Styled};
+
+#[test]
+fn nested_escape_sequences() {
+    // Resets in text that was styled elsewhere also restore the enclosing style.
+    let value = Styled::new("a\x1b[1mb\x1b[mc\x1b[00md").red();
+    assert_eq!(render(value), "\x1b[0;31ma\x1b[1mb\x1b[22mcd\x1b[0m");
+
+    // After a sequence that a style can't represent, the whole style is restored.
+    let value = Styled::new("a\x1b[1;10mb\x1b[mc").red();
+    assert_eq!(render(value), "\x1b[0;31ma\x1b[1;10mb\x1b[0;31mc\x1b[0m");
+    let long = format!("\x1b[{}1m", "0;".repeat(40));
+    let value = Styled::new(format!("a{long}b\x1b[mc")).red();
+    assert_eq!(
+        render(value),
+        format!("\x1b[0;31ma{long}b\x1b[0;31mc\x1b[0m")
+    );
+
+    // Other sequences are written unchanged, even if they are split across writes.
+    let value = styled!("{}{}{}", "a\x1b[0", "1m\x1b", "[0").red();
+    assert_eq!(render(value), "\x1b[0;31ma\x1b[01m\x1b[0\x1b[0m");
+}
+
+#[test]
+fn nested_formatting_options() {
+    let value = styled!("[{}]", Styled::new(42).red());
+
+    enabled(|| {
+        let cases = [
+            (
+                format!("{:>5}", Styled::new(42).red()),
+                "\x1b[0;31m   42\x1b[0m",
+            ),
+            (
+                format!("{:*^6}", Styled::new("ab").red()),
+                "\x1b[0;31m**ab**\x1b[0m",
+            ),
+            (
+                format!("{:5}", Styled::new(42).red()),
+                "\x1b[0;31m   42\x1b[0m",
+            ),
+            (
+                format!("{:5}", Styled::new("ab").red()),
+                "\x1b[0;31mab   \x1b[0m",
+            ),
+            (
+                format!("{:+05}", Styled::new(42).red()),
+                "\x1b[0;31m+0042\x1b[0m",
+            ),
+            (
+                format!("{:#x}", Styled::new(255).red()),
+                "\x1b[0;31m0xff\x1b[0m",
+            ),
+            (
+                format!("{:.2}", Styled::new(1.0).red()),
+                "\x1b[0;31m1.00\x1b[0m",
+            ),
+            (
+                format!("{:>4.1}", Styled::new("日本").red()),
+                "\x1b[0;31m  日\x1b[0m",
+            ),
+            (value.to_string(), "\x1b[0m[\x1b[0;31m42\x1b[0m]\x1b[0m"),
+        ];
+
+        for (actual, expected) in cases {
+            assert_eq!(actual, expected);
+        }
+    });
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::{ANSI_COLORS, LEVELS};
+use stylic::{AnsiColor, Color, ColorLevel};
+
+// Channel values of the 6x6x6 color cube in the 8-bit palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+#[test]
+fn no_colors() {
+    for level in [ColorLevel::None, ColorLevel::Attributes] {
+        assert_eq!(Color::Rgb(1, 2, 3).quantize(level), Color::Default);
+        assert_eq!(Color::Ansi256(100).quantize(level), Color::Default);
+        assert_eq!(Color::Ansi(AnsiColor::Red).quantize(level), Color::Default);
+    }
+
+    for level in LEVELS {
+        assert_eq!(Color::Default.quantize(level), Color::Default);
+    }
+}
+
+#[test]
+fn supported_colors_are_unchanged() {
+    for i in 0..=255 {
+        let color = Color::Ansi256(i);
+        assert_eq!(color.quantize(ColorLevel::Ansi256), color);
+        assert_eq!(color.quantize(ColorLevel::Rgb), color);
+    }
+
+    for color in ANSI_COLORS.map(Color::Ansi) {
+        for level in [ColorLevel::Ansi, ColorLevel::Ansi256, ColorLevel::Rgb] {
+            assert_eq!(color.quantize(level), color);
+        }
+    }
+
+    assert_eq!(
+        Color::Rgb(12, 34, 56).quantize(ColorLevel::Rgb),
+        Color::Rgb(12, 34, 56)
+    );
+}
+
+#[test]
+fn palette_colors_map_to_themselves() {
+    // The 16 colors of the 8-bit palette are the 4-bit colors.
+    for (i, color) in ANSI_COLORS.into_iter().enumerate() {
+        assert_eq!(
+            Color::Ansi256(i as u8).quantize(ColorLevel::Ansi),
+            Color::Ansi(color)
+        );
+    }
+
+    // Exact colors of the color cube and the gray ramp map to their own index.
+    for (ri, &r) in CUBE_LEVELS.iter().enumerate() {
+        for (gi, &g) in CUBE_LEVELS.iter().enumerate() {
+            for (bi, &b) in CUBE_LEVELS.iter().enumerate() {
+                let index = 16 + 36 * ri + 6 * gi + bi;
+                assert_eq!(
+                    Color::Rgb(r, g, b).quantize(ColorLevel::Ansi256),
+                    Color::Ansi256(index as u8),
+                    "rgb({r}, {g}, {b})"
+                );
+            }
+        }
+    }
+
+    for i in 0..24 {
+        let level = 8 + 10 * i;
+        assert_eq!(
+            Color::Rgb(level, level, level).quantize(ColorLevel::Ansi256),
+            Color::Ansi256(232 + i)
+        );
+    }
+}
+
+#[test]
+fn nearest_colors() {
+    let ansi256 = |r, g, b| Color::Rgb(r, g, b).quantize(ColorLevel::Ansi256);
+    let ansi = |r, g, b| Color::Rgb(r, g, b).quantize(ColorLevel::Ansi);
+
+    // Close to a color of the cube.
+    assert_eq!(ansi256(250, 5, 5), Color::Ansi256(196));
+    assert_eq!(ansi256(0, 100, 0), Color::Ansi256(22));
+
+    // Grays prefer the finer gray ramp over the cube.
+    assert_eq!(ansi256(130, 130, 130), Color::Ansi256(244));
+
+    assert_eq!(ansi(250, 5, 5), Color::Ansi(AnsiColor::BrightRed));
+    assert_eq!(ansi(180, 0, 0), Color::Ansi(AnsiColor::Red));
+    assert_eq!(ansi(10, 10, 10), Color::Ansi(AnsiColor::Black));
+    assert_eq!(ansi(250, 250, 250), Color::Ansi(AnsiColor::BrightWhite));
+
+    // 8-bit colors are mapped through their RGB value.
+    assert_eq!(
+        Color::Ansi256(196).quantize(ColorLevel::Ansi),
+        Color::Ansi(AnsiColor::BrightRed)
+    );
+    assert_eq!(
+        Color::Ansi256(244).quantize(ColorLevel::Ansi),
+        Color::Ansi(AnsiColor::BrightBlack)
+    );
+}
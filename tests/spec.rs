@@ -0,0 +1,162 @@
+use stylic::{AnsiColor, Color, ParseColorError, ParseStyleErrorKind, Style};
+
+#[test]
+fn parse_colors() {
+    assert_eq!("default".parse(), Ok(Color::Default));
+    assert_eq!("red".parse(), Ok(Color::Ansi(AnsiColor::Red)));
+    assert_eq!("Bright-Red".parse(), Ok(Color::Ansi(AnsiColor::BrightRed)));
+    assert_eq!("0".parse(), Ok(Color::Ansi256(0)));
+    assert_eq!("255".parse(), Ok(Color::Ansi256(255)));
+    assert_eq!("#f80".parse(), Ok(Color::Rgb(255, 136, 0)));
+    assert_eq!("#FF8800".parse(), Ok(Color::Rgb(255, 136, 0)));
+    assert_eq!("rgb(255, 136, 0)".parse(), Ok(Color::Rgb(255, 136, 0)));
+    assert_eq!("RGB(1,2,3)".parse(), Ok(Color::Rgb(1, 2, 3)));
+}
+
+#[test]
+fn parse_color_errors() {
+    let cases = [
+        ("", ParseColorError::Empty),
+        ("256", ParseColorError::InvalidIndex),
+        ("#ff880", ParseColorError::InvalidHex),
+        ("#ggg", ParseColorError::InvalidHex),
+        ("#+f+f+f", ParseColorError::InvalidHex),
+        ("rgb(1, 2)", ParseColorError::InvalidRgb),
+        ("rgb(1, 2, 3, 4)", ParseColorError::InvalidRgb),
+        ("rgb(1, 2, 256)", ParseColorError::InvalidRgb),
+        ("rgb(+1, 2, 3)", ParseColorError::InvalidRgb),
+        ("rgb(1, , 3)", ParseColorError::InvalidRgb),
+        ("rgb(1, 2, 3", ParseColorError::InvalidRgb),
+        ("+1", ParseColorError::UnknownName),
+        ("purple", ParseColorError::UnknownName),
+    ];
+
+    for (input, err) in cases {
+        assert_eq!(input.parse::<Color>(), Err(err), "{input:?}");
+    }
+
+    assert_eq!("".parse::<AnsiColor>(), Err(ParseColorError::Empty));
+    assert_eq!("1".parse::<AnsiColor>(), Err(ParseColorError::UnknownName));
+}
+
+#[test]
+fn color_round_trip() {
+    let mut colors = vec![Color::Default, Color::Rgb(255, 136, 0), Color::Rgb(0, 1, 2)];
+    colors.extend((0..16).map(|i| Color::Ansi256(i * 17)));
+    colors.extend(
+        ["black", "white", "bright_black", "bright_white"]
+            .map(|name| Color::Ansi(name.parse().unwrap())),
+    );
+
+    for color in colors {
+        assert_eq!(color.to_string().parse(), Ok(color), "{color:?}");
+    }
+}
+
+#[test]
+fn parse_styles() {
+    let cases = [
+        ("", Style::new()),
+        ("  bold\titalic ", Style::new().bold().italic()),
+        ("BOLD No-Bold", Style::new()),
+        (
+            "ul strike reverse",
+            Style::new().underlined().crossed().inverted(),
+        ),
+        ("curly_underlined", Style::new().curly_underlined()),
+        ("curly_underlined nounderlined", Style::new()),
+        (
+            "curly-underlined no_ul underlined",
+            Style::new().underlined(),
+        ),
+        ("red", Style::new().red()),
+        ("red blue", Style::new().red().on_blue()),
+        ("on blue red", Style::new().red().on_blue()),
+        (
+            "rgb(1, 2, 3) on #040506",
+            Style::new().rgb_color(1, 2, 3).on_color_rgb(4, 5, 6),
+        ),
+        (
+            "underline_color 208 dotted_underlined",
+            Style::new()
+                .underline_color(Color::Ansi256(208))
+                .dotted_underlined(),
+        ),
+    ];
+
+    for (spec, style) in cases {
+        assert_eq!(Style::parse(spec), Ok(style), "{spec:?}");
+    }
+}
+
+#[test]
+fn parse_style_errors() {
+    let cases = [
+        (
+            "bold purple",
+            ParseStyleErrorKind::InvalidColor(ParseColorError::UnknownName),
+            5,
+        ),
+        ("red on", ParseStyleErrorKind::ExpectedBackground, 4),
+        (
+            "on #12",
+            ParseStyleErrorKind::InvalidColor(ParseColorError::InvalidHex),
+            3,
+        ),
+        (
+            "underline_color",
+            ParseStyleErrorKind::ExpectedUnderlineColor,
+            0,
+        ),
+        ("red blue green", ParseStyleErrorKind::TooManyColors, 9),
+        ("on blue red green", ParseStyleErrorKind::TooManyColors, 12),
+        ("red blue on green", ParseStyleErrorKind::TooManyColors, 12),
+        ("on red on blue", ParseStyleErrorKind::TooManyColors, 10),
+        (
+            "bold rgb(1, 2)",
+            ParseStyleErrorKind::InvalidColor(ParseColorError::InvalidRgb),
+            5,
+        ),
+    ];
+
+    for (spec, kind, position) in cases {
+        let err = Style::parse(spec).unwrap_err();
+        assert_eq!((err.kind(), err.position()), (kind, position), "{spec:?}");
+    }
+}
+
+#[test]
+fn style_spec_round_trip() {
+    let styles = [
+        Style::new(),
+        Style::new().bold().dim().italic(),
+        Style::new().underlined(),
+        Style::new()
+            .curly_underlined()
+            .underline_color(Color::Rgb(1, 2, 3)),
+        Style::new().dashed_underlined().blinking().hidden(),
+        Style::new().red().on_bright_black(),
+        Style::new().on_color_ansi256(100),
+        Style::new()
+            .inverted()
+            .crossed()
+            .rapid_blinking()
+            .double_underlined()
+            .framed()
+            .encircled()
+            .overlined()
+            .superscript()
+            .subscript(),
+    ];
+
+    for style in styles {
+        let spec = style.to_spec().to_string();
+        assert_eq!(Style::parse(&spec), Ok(style), "{spec:?}");
+    }
+
+    assert_eq!(Style::new().to_spec().to_string(), "default");
+    assert_eq!(
+        Style::new().curly_underlined().bold().to_spec().to_string(),
+        "bold curly_underlined"
+    );
+}
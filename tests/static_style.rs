@@ -0,0 +1,51 @@
+//! Tests of escape sequences that are generated at compile time.
+
+mod common;
+
+use common::{render_style, ATTRIBUTES, LEVELS};
+use stylic::{with_style_mode, Attributes, Color, StaticStyle, Style, StyleMode, Styled};
+
+#[test]
+fn const_sgr() {
+    const STYLE: Style = Style::new().red().on_blue().bold().italic();
+    const SGR: &str = stylic::sgr!(STYLE);
+
+    assert_eq!(SGR, "\x1b[0;31;44;3;1m");
+    assert_eq!(render_style(STYLE), format!("{SGR}x\x1b[0m"));
+
+    // The longest possible sequence.
+    let style = Style::new()
+        .rgb_color(255, 255, 255)
+        .on_color_rgb(255, 255, 255)
+        .attributes(
+            ATTRIBUTES
+                .into_iter()
+                .fold(Attributes::EMPTY, |all, (attribute, _)| all | attribute),
+        )
+        .curly_underlined()
+        .underline_color(Color::Rgb(255, 255, 255));
+    assert_eq!(style.sgr().len(), 93);
+    assert_eq!(render_style(style), format!("{}x\x1b[0m", style.sgr()));
+}
+
+#[test]
+fn static_style() {
+    static STYLE: StaticStyle =
+        StaticStyle::new(Style::new().rgb_color(255, 136, 0).on_blue().bold());
+
+    assert_eq!(
+        STYLE.style(),
+        Style::new().rgb_color(255, 136, 0).on_blue().bold()
+    );
+
+    for level in LEVELS {
+        assert_eq!(STYLE.sgr_at(level), &STYLE.style().sgr_at(level));
+
+        with_style_mode(StyleMode::Force(level), || {
+            assert_eq!(
+                format!("{:>4x}", STYLE.apply(255)),
+                format!("{:>4x}", Styled::new(255).style(STYLE.style())),
+            );
+        });
+    }
+}
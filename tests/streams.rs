@@ -0,0 +1,118 @@
+use std::fmt;
+use std::{panic, thread};
+
+use stylic::{
+    current_color_level, get_style_mode, is_style_enabled, strip_ansi, with_style_mode, ColorLevel,
+    Stream, StyleMode, Styled,
+};
+
+/// Records the style mode that it is rendered with.
+struct RecordMode;
+
+impl fmt::Display for RecordMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", get_style_mode())
+    }
+}
+
+struct Panic;
+
+impl fmt::Display for Panic {
+    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
+        panic!("rendering failed");
+    }
+}
+
+// The style modes are global, so everything that changes them is in a single test.
+#[test]
+fn per_stream_modes() {
+    let ansi = StyleMode::Force(ColorLevel::Ansi);
+
+    Stream::Stdout.set_style_mode(StyleMode::disable());
+    assert!(Stream::Stderr.set_style_mode(StyleMode::enable()));
+    assert!(Stream::Custom.set_style_mode(ansi));
+
+    assert_eq!(Stream::Stdout.style_mode(), StyleMode::disable());
+    assert_eq!(Stream::Stderr.color_level(), ColorLevel::Rgb);
+    assert_eq!(Stream::Custom.color_level(), ColorLevel::Ansi);
+    assert!(!Stream::Stdout.is_style_enabled());
+
+    let red = Styled::new("x").red();
+    assert_eq!(red.to_string(), "x");
+    assert_eq!(red.for_stream(Stream::Stdout).to_string(), "x");
+    assert_eq!(
+        red.for_stream(Stream::Stderr).to_string(),
+        "\x1b[0;31mx\x1b[0m"
+    );
+    assert_eq!(
+        Styled::new("x")
+            .rgb_color(255, 0, 0)
+            .for_stream(Stream::Custom)
+            .to_string(),
+        "\x1b[0;91mx\x1b[0m"
+    );
+
+    // Values nested inside are rendered for the same stream.
+    let nested = Styled::new(RecordMode).for_stream(Stream::Custom);
+    assert_eq!(strip_ansi(&nested.to_string()), format!("{ansi:?}"));
+    assert_eq!(
+        RecordMode.to_string(),
+        format!("{:?}", StyleMode::disable())
+    );
+
+    // The stream is restored even if rendering panics.
+    let result = panic::catch_unwind(|| Styled::new(Panic).for_stream(Stream::Stderr).to_string());
+    assert!(result.is_err());
+    assert_eq!(current_color_level(), ColorLevel::None);
+    assert_eq!(get_style_mode(), StyleMode::disable());
+
+    assert!(stylic::set_style_mode(StyleMode::enable()));
+    for stream in [Stream::Stdout, Stream::Stderr, Stream::Custom] {
+        assert_eq!(stream.style_mode(), StyleMode::enable());
+    }
+}
+
+#[test]
+fn style_mode_override() {
+    let attributes = StyleMode::Force(ColorLevel::Attributes);
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(get_style_mode(), StyleMode::disable());
+        assert!(!is_style_enabled());
+        assert_eq!(Styled::new("x").red().to_string(), "x");
+
+        // The override applies to every stream.
+        for stream in [Stream::Stdout, Stream::Stderr, Stream::Custom] {
+            assert_eq!(stream.style_mode(), StyleMode::disable());
+        }
+        assert_eq!(
+            Styled::new("x")
+                .red()
+                .for_stream(Stream::Stderr)
+                .to_string(),
+            "x"
+        );
+
+        with_style_mode(attributes, || {
+            assert_eq!(get_style_mode(), attributes);
+            assert_eq!(current_color_level(), ColorLevel::Attributes);
+            assert_eq!(
+                Styled::new("x").red().bold().to_string(),
+                "\x1b[0;1mx\x1b[0m"
+            );
+        });
+
+        assert_eq!(get_style_mode(), StyleMode::disable());
+
+        // The override is restored even if the closure panics.
+        let result = panic::catch_unwind(|| with_style_mode(attributes, || panic!()));
+        assert!(result.is_err());
+        assert_eq!(get_style_mode(), StyleMode::disable());
+
+        // Other threads aren't affected.
+        with_style_mode(attributes, || {
+            let mode = thread::spawn(get_style_mode).join().unwrap();
+            assert_ne!(mode, attributes);
+        });
+    });
+}
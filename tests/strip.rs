@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+use stylic::{hyperlink, strip_ansi, styled, with_style_mode, StripWriter, Style, StyleMode};
+
+#[test]
+fn plain_text_is_borrowed() {
+    assert!(matches!(strip_ansi("hello"), Cow::Borrowed("hello")));
+}
+
+#[test]
+fn rendered_output() {
+    with_style_mode(StyleMode::enable(), || {
+        let styled = format!(
+            "{} {}",
+            styled!("hello")
+                .bold()
+                .rgb_color(1, 2, 3)
+                .curly_underlined(),
+            hyperlink!("https://example.com"; "link").red(),
+        );
+        assert_eq!(strip_ansi(&styled), "hello link");
+
+        let transition = format!(
+            "a{}b",
+            Style::new().bold().transition_to(Style::new().red())
+        );
+        assert_eq!(strip_ansi(&transition), "ab");
+    });
+}
+
+#[test]
+fn sequences() {
+    // CSI, OSC terminated by BEL and ST, and other escape sequences.
+    assert_eq!(strip_ansi("a\x1b[2Jb\x1b[?25lc"), "abc");
+    assert_eq!(strip_ansi("a\x1b]0;title\x07b\x1b]8;;uri\x1b\\c"), "abc");
+    assert_eq!(strip_ansi("a\x1b(Bb\x1b=c\x1b7d"), "abcd");
+    assert_eq!(strip_ansi("日本\x1b[1m語"), "日本語");
+}
+
+#[test]
+fn malformed_input() {
+    // Sequences end before unexpected characters, which are kept.
+    assert_eq!(strip_ansi("a\x1b[1\x07b"), "a\x07b");
+    assert_eq!(strip_ansi("a\x1b[1日b"), "a日b");
+    assert_eq!(strip_ansi("a\x1b\x07b"), "a\x07b");
+
+    // An interrupted OSC is dropped, and the interrupting sequence is removed.
+    assert_eq!(strip_ansi("a\x1b]8;;uri\x1b[1mb"), "ab");
+
+    // Unterminated sequences at the end are dropped.
+    assert_eq!(strip_ansi("a\x1b"), "a");
+    assert_eq!(strip_ansi("a\x1b[1;3"), "a");
+    assert_eq!(strip_ansi("a\x1b]8;;uri"), "a");
+}
+
+const INPUT: &str = "x\x1b[1;38;2;1;2;3m日本\x1b]8;;https://example.com\x1b\\語\x1b]8;;\x07\x1b(By";
+const OUTPUT: &str = "x日本語y";
+
+#[test]
+fn fmt_writer_chunks() {
+    for chunk_size in 1..INPUT.len() {
+        let mut writer = StripWriter::new(String::new());
+
+        let mut rest = INPUT;
+        while !rest.is_empty() {
+            let mut mid = chunk_size.min(rest.len());
+            while !rest.is_char_boundary(mid) {
+                mid += 1;
+            }
+
+            let (chunk, tail) = rest.split_at(mid);
+            writer.write_str(chunk).unwrap();
+            rest = tail;
+        }
+
+        assert_eq!(writer.into_inner(), OUTPUT, "chunk size {chunk_size}");
+    }
+}
+
+#[test]
+fn io_writer_chunks() {
+    // Chunks may split multi-byte characters as well as escape sequences.
+    for chunk_size in 1..INPUT.len() {
+        let mut writer = StripWriter::new(Vec::new());
+
+        for chunk in INPUT.as_bytes().chunks(chunk_size) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert_eq!(
+            writer.into_inner(),
+            OUTPUT.as_bytes(),
+            "chunk size {chunk_size}"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Tests of the `Stylize` extension trait.
+
+mod common;
+
+use common::{enabled, expected};
+use stylic::{Attributes, Style};
+
+#[test]
+fn stylize() {
+    use stylic::Stylize;
+
+    enabled(|| {
+        assert_eq!("x".red().bold().to_string(), expected(";31;1"));
+        assert_eq!(
+            String::from("x").curly_underlined().to_string(),
+            expected(";4:3")
+        );
+        assert_eq!(
+            (&&'x').on_color_ansi256(21).to_string(),
+            expected(";48;5;21")
+        );
+        assert_eq!(
+            "x".attributes(Attributes::ITALIC).inherit().value.style,
+            Style::new().italic()
+        );
+    });
+}
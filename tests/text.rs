@@ -0,0 +1,129 @@
+mod common;
+
+use common::render;
+use stylic::{styled, with_style_mode, Color, Style, StyleMode, Styled, StyledText};
+
+#[test]
+fn push() {
+    let mut text = StyledText::new();
+    assert!(text.is_empty());
+
+    text.push(Styled::new("a").red());
+    text.push(Styled::new(1).red());
+    text.push(Styled::new("").bold());
+    text.push(styled!("{}", 'b').bold());
+
+    let spans: Vec<_> = text
+        .iter()
+        .map(|span| (span.style, span.content.as_str()))
+        .collect();
+    assert_eq!(
+        spans,
+        [(Style::new().red(), "a1"), (Style::new().bold(), "b")]
+    );
+
+    // Only the changes are written between spans.
+    assert_eq!(render(&text), "\x1b[0;31ma1\x1b[1;39mb\x1b[0m");
+    assert_eq!(text.visible_width(), 3);
+}
+
+#[test]
+fn combine() {
+    let a = StyledText::from(Styled::new("a").red());
+    let b: StyledText = [Styled::new("b").red(), Styled::new("c").blue()]
+        .into_iter()
+        .collect();
+
+    let mut c = a.clone() + b.clone();
+    assert_eq!(c.spans().len(), 2);
+    assert_eq!(c.spans()[0].content, "ab");
+
+    c += Styled::new("d").blue();
+    c.extend([Styled::new("e")]);
+    c.extend([a.clone(), b]);
+    let styles: Vec<_> = c.iter().map(|span| span.style).collect();
+    assert_eq!(
+        styles,
+        [
+            Style::new().red(),
+            Style::new().blue(),
+            Style::new(),
+            Style::new().red(),
+            Style::new().blue(),
+        ]
+    );
+
+    let contents: Vec<_> = c.into_iter().map(|span| span.content).collect();
+    assert_eq!(contents, ["ab", "cd", "e", "ab", "c"]);
+}
+
+#[test]
+fn formatting_options() {
+    let text = StyledText::from(Styled::new("ab").red()) + Styled::new("cd");
+
+    with_style_mode(StyleMode::enable(), || {
+        assert_eq!(format!("[{text:>6}]"), "[  \x1b[0;31mab\x1b[0mcd]");
+        assert_eq!(format!("[{text:.3}]"), "[\x1b[0;31mab\x1b[0mc]");
+    });
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(format!("[{text:<6}]"), "[abcd  ]");
+    });
+}
+
+#[test]
+fn transitions() {
+    let text = StyledText::from(Styled::new("a").red().bold())
+        + Styled::new("b").red()
+        + Styled::new("c").blue().italic();
+    assert_eq!(render(&text), "\x1b[0;31;1ma\x1b[22mb\x1b[3;34mc\x1b[0m");
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(text.to_string(), "abc");
+    });
+
+    assert_eq!(render(StyledText::new()), "");
+}
+
+#[test]
+fn escapes_in_content() {
+    // Content that contains escape sequences is written on its own, since it may
+    // change the style.
+    let text = StyledText::from(Styled::new("a").red())
+        + Styled::new("\x1b[1mb").red().italic()
+        + Styled::new("c").red();
+    assert_eq!(
+        render(&text),
+        "\x1b[0;31ma\x1b[0;31;3m\x1b[1mb\x1b[0m\x1b[0;31mc\x1b[0m"
+    );
+}
+
+#[test]
+fn longest_transition() {
+    let white = Color::Rgb(255, 255, 255);
+    let from = Style::new()
+        .bold()
+        .double_underlined()
+        .blinking()
+        .framed()
+        .superscript()
+        .italic()
+        .inverted()
+        .hidden()
+        .crossed()
+        .overlined();
+    let to = Style::new()
+        .dim()
+        .curly_underlined()
+        .rapid_blinking()
+        .encircled()
+        .subscript()
+        .fg(white)
+        .bg(white)
+        .underline_color(white);
+
+    let text = StyledText::from(Styled::new("a").style(from)) + Styled::new("b").style(to);
+    let rendered = render(&text);
+    assert!(rendered.contains(&from.transition_to(to).to_string()));
+    assert_eq!(stylic::strip_ansi(&rendered), "ab");
+}
@@ -0,0 +1,128 @@
+//! Tests that transitions between styles produce the target style.
+
+use stylic::{
+    parse_ansi, with_style_mode, AnsiColor, Color, ColorLevel, Style, StyleMode, UnderlineStyle,
+};
+
+fn styles() -> Vec<Style> {
+    let base = [
+        Style::new(),
+        Style::new().bold(),
+        Style::new().dim(),
+        Style::new().bold().dim(),
+        Style::new().italic().crossed(),
+        Style::new().underlined(),
+        Style::new().double_underlined(),
+        Style::new().curly_underlined(),
+        Style::new()
+            .dashed_underlined()
+            .underline_color(Color::Rgb(1, 2, 3)),
+        Style::new().underline_color(Color::Ansi256(100)),
+        Style::new().blinking().rapid_blinking(),
+        Style::new().inverted().hidden().overlined(),
+        Style::new().framed().encircled(),
+        Style::new().superscript(),
+        Style::new().subscript(),
+    ];
+
+    let colors = [
+        (Color::Default, Color::Default),
+        (Color::Ansi(AnsiColor::Red), Color::Default),
+        (Color::Default, Color::Ansi(AnsiColor::BrightBlue)),
+        (Color::Ansi256(208), Color::Rgb(10, 20, 30)),
+    ];
+
+    base.iter()
+        .flat_map(|style| colors.map(|(fg, bg)| style.fg(fg).bg(bg)))
+        .collect()
+}
+
+/// The style that a terminal ends up with after writing `from` and then transitioning
+/// to `to`.
+fn apply(from: Style, to: Style) -> Style {
+    let s = format!("{from}{}", from.transition_to(to));
+    let mut parser = parse_ansi(&s);
+    parser.by_ref().for_each(drop);
+    parser.style()
+}
+
+#[test]
+fn round_trip() {
+    with_style_mode(StyleMode::enable(), || {
+        let styles = styles();
+
+        for &from in &styles {
+            for &to in &styles {
+                assert_eq!(apply(from, to), to, "{from:?} -> {to:?}");
+            }
+        }
+    });
+}
+
+#[test]
+fn same_style() {
+    with_style_mode(StyleMode::enable(), || {
+        for style in styles() {
+            assert_eq!(style.transition_to(style).to_string(), "");
+        }
+    });
+}
+
+#[test]
+fn underline_style_only() {
+    with_style_mode(StyleMode::enable(), || {
+        let from = Style::new().curly_underlined();
+        let to = Style::new().underline_style(UnderlineStyle::Dotted);
+        assert_eq!(from.transition_to(to).to_string(), "\x1b[4:4m");
+    });
+}
+
+#[test]
+fn color_levels() {
+    let from = Style::new().bold().rgb_color(255, 0, 0);
+    let to = Style::new().rgb_color(0, 0, 255);
+
+    with_style_mode(StyleMode::disable(), || {
+        assert_eq!(from.transition_to(to).to_string(), "");
+    });
+
+    // Colors are quantized before comparing, so the colors don't matter here.
+    with_style_mode(StyleMode::Force(ColorLevel::Attributes), || {
+        assert_eq!(from.transition_to(to).to_string(), "\x1b[0m");
+    });
+
+    with_style_mode(StyleMode::Force(ColorLevel::Ansi), || {
+        assert_eq!(from.transition_to(to).to_string(), "\x1b[22;34m");
+    });
+}
+
+#[test]
+fn longest() {
+    with_style_mode(StyleMode::enable(), || {
+        // Every group of attributes is turned off and then partly set again, along
+        // with three RGB colors.
+        let from = Style::new()
+            .bold()
+            .double_underlined()
+            .blinking()
+            .framed()
+            .superscript()
+            .italic()
+            .inverted()
+            .hidden()
+            .crossed()
+            .overlined();
+        let to = Style::new()
+            .dim()
+            .curly_underlined()
+            .rapid_blinking()
+            .encircled()
+            .subscript()
+            .fg(Color::Rgb(255, 255, 255))
+            .bg(Color::Rgb(255, 255, 255))
+            .underline_color(Color::Rgb(255, 255, 255));
+
+        assert_eq!(from.transition_to(to).to_string().len(), 97);
+        assert_eq!(apply(from, to), to);
+    });
+}
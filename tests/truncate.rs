@@ -0,0 +1,146 @@
+mod common;
+
+use common::render;
+use stylic::{hyperlink, strip_ansi, with_style_mode, StyleMode, Styled, StyledText, Truncated};
+
+#[test]
+fn fits() {
+    assert_eq!(
+        render(Styled::new("hello").truncate(5, "…")),
+        "\x1b[0mhello\x1b[0m"
+    );
+    assert_eq!(
+        render(Styled::new("hello").red().truncate(10, "…")),
+        "\x1b[0;31mhello\x1b[0m"
+    );
+    assert_eq!(render(Truncated::new("", 0, "…")), "");
+}
+
+#[test]
+fn plain() {
+    assert_eq!(render(Truncated::new("hello world", 8, "…")), "hello w…");
+    assert_eq!(render(Truncated::new("hello world", 8, "...")), "hello...");
+    assert_eq!(render(Truncated::new("hello world", 8, "")), "hello wo");
+    assert_eq!(render(Truncated::new("hello world", 0, "")), "");
+
+    // An ellipsis that doesn't fit is truncated itself.
+    assert_eq!(render(Truncated::new("hello world", 2, "...")), "..");
+    assert_eq!(render(Truncated::new("hello", 1, "...")), ".");
+    assert_eq!(render(Truncated::new("hello", 0, "...")), "");
+}
+
+#[test]
+fn grapheme_clusters() {
+    // Wide characters aren't split.
+    assert_eq!(render(Truncated::new("日本語", 4, "…")), "日…");
+    assert_eq!(render(Truncated::new("日本語", 5, "")), "日本");
+
+    // Combining marks, joined emoji and flags stay with the preceding character.
+    assert_eq!(
+        render(Truncated::new("ae\u{301}e\u{301}x", 3, "…")),
+        "ae\u{301}…"
+    );
+    assert_eq!(
+        render(Truncated::new("a👨\u{200d}👩\u{200d}👧b", 3, "")),
+        "a👨\u{200d}👩\u{200d}👧"
+    );
+    assert_eq!(render(Truncated::new("a🇬🇧🇫🇷", 4, "…")), "a🇬🇧…");
+
+    // Skin tone modifiers stay with the emoji.
+    assert_eq!(render(Truncated::new("👍🏽x", 2, "")), "👍🏽");
+    assert_eq!(render(Truncated::new("a👍🏽x", 2, "")), "a");
+
+    // An emoji presentation selector that doesn't fit leaves the symbol narrow.
+    assert_eq!(render(Truncated::new("a❤\u{fe0f}b", 3, "")), "a❤\u{fe0f}");
+    assert_eq!(render(Truncated::new("a❤\u{fe0f}b", 2, "")), "a❤");
+}
+
+#[test]
+fn styles_are_closed() {
+    assert_eq!(
+        render(Styled::new("hello world").red().truncate(6, "…")),
+        "\x1b[0;31mhello\x1b[0m…"
+    );
+
+    assert_eq!(
+        render(
+            Styled::new("hello world")
+                .red()
+                .truncate(6, Styled::new("…").dim())
+        ),
+        "\x1b[0;31mhello\x1b[0m\x1b[0;2m…\x1b[0m"
+    );
+    assert_eq!(
+        render(
+            Styled::new("hello world")
+                .red()
+                .truncate(2, Styled::new("...").dim())
+        ),
+        "\x1b[0;2m..\x1b[0m"
+    );
+
+    // Text that is already rendered is handled the same way, and sequences after the
+    // cut are dropped.
+    let rendered = render(
+        StyledText::from(Styled::new("ab").bold()) + Styled::new("cd") + Styled::new("ef").red(),
+    );
+    assert_eq!(
+        render(Truncated::new(rendered.as_str(), 3, "")),
+        "\x1b[0;1mab\x1b[0mc"
+    );
+    assert_eq!(
+        render(Truncated::new(rendered.as_str(), 2, "")),
+        "\x1b[0;1mab\x1b[0m"
+    );
+
+    // A reset that was already written isn't repeated.
+    assert_eq!(
+        render(Truncated::new("\x1b[1mab\x1b[0mcd", 3, "")),
+        "\x1b[1mab\x1b[0mc"
+    );
+}
+
+#[test]
+fn hyperlinks_are_closed() {
+    assert_eq!(
+        render(hyperlink!("https://example.com"; "example").truncate(4, "…")),
+        "\x1b]8;;https://example.com\x1b\\\x1b[0mexa\x1b]8;;\x1b\\…"
+    );
+
+    let rendered = render(Truncated::new(
+        "\x1b]8;;uri\x07link\x1b]8;;\x07 text",
+        6,
+        "",
+    ));
+    assert_eq!(rendered, "\x1b]8;;uri\x07link\x1b]8;;\x07 t");
+}
+
+#[test]
+fn disabled() {
+    with_style_mode(StyleMode::disable(), || {
+        let text = StyledText::from(Styled::new("hello").bold()) + Styled::new(" world").red();
+        assert_eq!(
+            text.truncate(8, Styled::new("…").dim()).to_string(),
+            "hello w…"
+        );
+    });
+}
+
+#[test]
+fn formatting_options() {
+    let truncated = Styled::new("hello world").truncate(6, "…");
+    assert_eq!(
+        strip_ansi(&render(format_args!("[{truncated:>8}]"))),
+        "[  hello…]"
+    );
+}
+
+#[cfg(feature = "nested_styles")]
+#[test]
+fn nested() {
+    let inner = Styled::new("hello world").bold().truncate(6, "…");
+    assert_eq!(
+        render(stylic::styled!("[{inner}]").red()),
+        "\x1b[0;31m[\x1b[0;1mhello\x1b[22;31m…]\x1b[0m"
+    );
+}
@@ -0,0 +1,66 @@
+//! Tests of underline styles and underline colors.
+
+mod common;
+
+use common::{expected, render_style};
+use stylic::{with_style_mode, AnsiColor, Color, ColorLevel, Style, StyleMode, Styled};
+
+#[test]
+fn underline_styles() {
+    assert_eq!(render_style(Style::new().underlined()), expected(";4"));
+    assert_eq!(
+        render_style(Style::new().curly_underlined()),
+        expected(";4:3")
+    );
+    assert_eq!(
+        render_style(Style::new().dotted_underlined()),
+        expected(";4:4")
+    );
+    assert_eq!(
+        render_style(Style::new().dashed_underlined().bold().italic()),
+        expected(";3;1;4:5")
+    );
+    assert_eq!(
+        render_style(
+            Style::new()
+                .curly_underlined()
+                .underline_color(Color::Rgb(255, 0, 0))
+        ),
+        expected(";4:3;58;2;255;0;0")
+    );
+    assert_eq!(
+        render_style(
+            Style::new()
+                .underlined()
+                .underline_color(Color::Ansi256(196))
+        ),
+        expected(";4;58;5;196")
+    );
+    assert_eq!(
+        render_style(
+            Style::new()
+                .underlined()
+                .underline_color(Color::Ansi(AnsiColor::Red))
+        ),
+        expected(";4;58;5;1")
+    );
+}
+
+#[test]
+fn underline_styles_degrade() {
+    let style = Style::new()
+        .curly_underlined()
+        .underline_color(Color::Rgb(255, 0, 0))
+        .bold();
+
+    for level in [
+        ColorLevel::Ansi256,
+        ColorLevel::Ansi,
+        ColorLevel::Attributes,
+    ] {
+        let rendered = with_style_mode(StyleMode::Force(level), || {
+            Styled::new("x").style(style).to_string()
+        });
+        assert_eq!(rendered, expected(";4;1"));
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::Write;
+
+use stylic::{hyperlink, styled, with_style_mode, Style, StyleMode, Styled, WidthCounter};
+
+fn width(s: &str) -> usize {
+    let mut counter = WidthCounter::new();
+    counter.write_str(s).unwrap();
+    counter.width()
+}
+
+#[test]
+fn characters() {
+    assert_eq!(width(""), 0);
+    assert_eq!(width("hello"), 5);
+    assert_eq!(width("日本語"), 6);
+    assert_eq!(width("ｈｉ"), 4);
+    assert_eq!(width("한국어"), 6);
+    assert_eq!(width("ｱｲｳ"), 3);
+    assert_eq!(width("αβγ"), 3);
+}
+
+#[test]
+fn zero_width() {
+    assert_eq!(width("e\u{301}"), 1);
+    assert_eq!(width("a\u{200b}b"), 2);
+    assert_eq!(width("\t\n\x07"), 0);
+    assert_eq!(width("\u{1100}\u{1161}\u{11a8}"), 2);
+}
+
+#[test]
+fn emoji() {
+    assert_eq!(width("🦀"), 2);
+    assert_eq!(width("👍🏽"), 2);
+    assert_eq!(width("👍🏽👍"), 4);
+    assert_eq!(width("🏽"), 2);
+    assert_eq!(width("❤\u{fe0f}"), 2);
+    assert_eq!(width("❤"), 1);
+    assert_eq!(width("1\u{fe0f}\u{20e3}"), 2);
+    assert_eq!(width("a\u{fe0f}b"), 2);
+    assert_eq!(width("👨\u{200d}👩\u{200d}👧"), 2);
+    assert_eq!(width("🇬🇧"), 2);
+}
+
+#[test]
+fn escapes_are_not_counted() {
+    assert_eq!(width("\x1b[1;38;2;1;2;3m日本\x1b[0m"), 4);
+    assert_eq!(
+        width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"),
+        4
+    );
+
+    // Escape sequences may be split across writes.
+    let mut counter = WidthCounter::new();
+    for chunk in ["ab\x1b", "[1", ";31", "mcd\x1b]8;;ur", "i\x1b", "\\e"] {
+        counter.write_str(chunk).unwrap();
+    }
+    assert_eq!(counter.width(), 5);
+}
+
+#[test]
+fn styled_values() {
+    for mode in [StyleMode::enable(), StyleMode::disable()] {
+        with_style_mode(mode, || {
+            assert_eq!(Styled::new("日本語").bold().red().visible_width(), 6);
+            assert_eq!(Styled::new(12345).visible_width(), 5);
+            assert_eq!(
+                styled!("{} {}", "a", styled!("日本").red())
+                    .bold()
+                    .visible_width(),
+                6
+            );
+            assert_eq!(
+                hyperlink!("https://example.com"; "{}", Styled::new("link").style(Style::new().blue()))
+                    .visible_width(),
+                4
+            );
+        });
+    }
+}